@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+
+use common::read_input;
+use serde::Deserialize;
+
+use crate::{workspace_path, Record, DAYS};
+
+/// Expected answers of a day for one input file.
+#[derive(Debug, Deserialize)]
+pub struct Answer {
+    pub day: u8,
+    /// path of the input, relative to the workspace root
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answer {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Answers known to be correct, loaded from `answers.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Registry {
+    #[serde(rename = "answer")]
+    pub answers: Vec<Answer>,
+}
+
+/// Result of checking one part of an [`Answer`].
+#[derive(Debug)]
+pub struct Check<'a> {
+    pub answer: &'a Answer,
+    pub part: u8,
+    pub expected: &'a str,
+    /// the solver's output, or why it couldn't produce one
+    pub actual: Result<String, String>,
+}
+
+impl Check<'_> {
+    pub fn passed(&self) -> bool {
+        // multi-line answers such as day 10's screen end with a newline
+        matches!(&self.actual, Ok(a) if a.trim_end() == self.expected.trim_end())
+    }
+}
+
+impl fmt::Display for Check<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} part {} ({}): ",
+            self.answer.day, self.part, self.answer.input
+        )?;
+        match &self.actual {
+            _ if self.passed() => write!(f, "ok"),
+            Ok(actual) => write!(
+                f,
+                "MISMATCH, expected {:?} got {:?}",
+                self.expected.trim_end(),
+                actual.trim_end()
+            ),
+            Err(e) => write!(f, "ERROR, {}", e),
+        }
+    }
+}
+
+impl Registry {
+    pub fn default_path() -> String {
+        workspace_path("answers.toml")
+    }
+
+    pub fn load(path: &str) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(toml::from_str(&content)?)
+    }
+
+    /// Path of the fixtures listing the puzzle statement's examples of a day.
+    pub fn examples_path(day: u8) -> String {
+        workspace_path(&format!("day{}/examples/answers.toml", day))
+    }
+
+    /// Loads the example fixtures of every day.
+    pub fn examples() -> Result<Self, Box<dyn Error>> {
+        let mut answers = Vec::new();
+        for day in 1..=DAYS.len() as u8 {
+            let path = Registry::examples_path(day);
+            let examples = Registry::load(&path).map_err(|e| format!("{}: {}", path, e))?;
+            answers.extend(examples.answers);
+        }
+        Ok(Registry { answers })
+    }
+
+    /// Runs the solvers on every registered input, optionally restricted to
+    /// a single day.
+    pub fn verify(&self, day: Option<u8>) -> Vec<Check<'_>> {
+        let mut checks = Vec::new();
+
+        for answer in self
+            .answers
+            .iter()
+            .filter(|a| day.is_none() || day == Some(a.day))
+        {
+            let solver = DAYS.get((answer.day as usize).wrapping_sub(1));
+            let actual: Result<Vec<Record>, String> = match solver {
+                Some(solver) => read_input(&workspace_path(&answer.input))
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| {
+                        (solver.run)(answer.day, &content, None).map_err(|e| e.to_string())
+                    }),
+                None => Err(format!("there is no solver for day {}", answer.day)),
+            };
+
+            for part in 1..=2 {
+                if let Some(expected) = answer.expected(part) {
+                    let actual = match &actual {
+                        Ok(records) => Ok(records[(part - 1) as usize].answer.clone()),
+                        Err(e) => Err(e.clone()),
+                    };
+                    checks.push(Check {
+                        answer,
+                        part,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        checks
+    }
+}
@@ -0,0 +1,181 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+
+/// System allocator that keeps track of how many allocations were made, so
+/// benchmarks can report them next to the timings.
+pub struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Timings of one step (parse, part 1 or part 2) over all iterations.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+    /// allocations made by a single run of the step
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10} median {:>10} max {:>10} allocs {:>8} ({} bytes)",
+            format_ns(self.min_ns),
+            format_ns(self.median_ns),
+            format_ns(self.max_ns),
+            self.allocations,
+            self.bytes
+        )
+    }
+}
+
+pub fn format_ns(ns: u64) -> String {
+    match ns {
+        0..=9_999 => format!("{}ns", ns),
+        10_000..=9_999_999 => format!("{:.1}µs", ns as f64 / 1e3),
+        _ => format!("{:.1}ms", ns as f64 / 1e6),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+}
+
+/// Saved benchmark results, compared against by later runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: Vec<DayBench>,
+}
+
+impl Baseline {
+    pub fn day(&self, day: u8) -> Option<&DayBench> {
+        self.days.iter().find(|d| d.day == day)
+    }
+}
+
+fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    let mut times = Vec::with_capacity(iterations);
+    let mut allocations = 0;
+    let mut bytes = 0;
+
+    for i in 0..iterations {
+        let (count_before, bytes_before) = (
+            ALLOCATIONS.load(Ordering::Relaxed),
+            ALLOCATED_BYTES.load(Ordering::Relaxed),
+        );
+        let start = Instant::now();
+        let output = black_box(f());
+        let elapsed = start.elapsed();
+        if i == 0 {
+            allocations = ALLOCATIONS.load(Ordering::Relaxed) - count_before;
+            bytes = ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes_before;
+        }
+        drop(output);
+        times.push(elapsed.as_nanos() as u64);
+    }
+
+    times.sort();
+    Stats {
+        min_ns: times[0],
+        median_ns: times[times.len() / 2],
+        max_ns: times[times.len() - 1],
+        allocations,
+        bytes,
+    }
+}
+
+/// Runs parse, part 1 and part 2 of a day `iterations` times each.
+pub fn bench<S: Solution>(day: u8, input: &str, iterations: usize) -> Result<DayBench, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(DayBench {
+        day,
+        parse: measure(iterations, || S::parse(black_box(input))),
+        part1: measure(iterations, || S::part1(black_box(&parsed))),
+        part2: measure(iterations, || S::part2(black_box(&parsed))),
+    })
+}
+
+/// Whether `current` is slower than `baseline` by more than `tolerance`
+/// percent, looking at medians.
+pub fn is_regression(current: &Stats, baseline: &Stats, tolerance: f64) -> bool {
+    current.median_ns as f64 > baseline.median_ns as f64 * (1.0 + tolerance / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: 0,
+            median_ns,
+            max_ns: 0,
+            allocations: 0,
+            bytes: 0,
+        }
+    }
+
+    #[test]
+    fn test_measure() {
+        let s = measure(5, || vec![1u8; 16]);
+        assert!(s.min_ns <= s.median_ns && s.median_ns <= s.max_ns);
+        assert!(s.allocations >= 1);
+        assert!(s.bytes >= 16);
+    }
+
+    #[test]
+    fn test_is_regression() {
+        assert!(!is_regression(&stats(105), &stats(100), 10.0));
+        assert!(is_regression(&stats(111), &stats(100), 10.0));
+    }
+
+    #[test]
+    fn test_format_ns() {
+        assert_eq!(format_ns(512), "512ns");
+        assert_eq!(format_ns(25_300), "25.3µs");
+        assert_eq!(format_ns(42_000_000), "42.0ms");
+    }
+}
@@ -0,0 +1,174 @@
+use std::time::Instant;
+
+use bench::{CountingAlloc, DayBench};
+use common::{ParseError, Solution};
+use serde::Serialize;
+
+pub mod answers;
+pub mod bench;
+pub mod query;
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+// parses the input and returns the answers of the requested parts
+pub type Runner = fn(u8, &str, Option<u8>) -> Result<Vec<Record>, ParseError>;
+
+// times parse and both parts of a day over a number of iterations
+pub type Bencher = fn(u8, &str, usize) -> Result<DayBench, ParseError>;
+
+pub struct Day {
+    pub input: &'static str,
+    pub run: Runner,
+    pub bench: Bencher,
+}
+
+pub const DAYS: [Day; 13] = [
+    Day {
+        input: "day1/input.txt",
+        run: run::<day1::Day1>,
+        bench: bench::bench::<day1::Day1>,
+    },
+    Day {
+        input: "day2/input.txt",
+        run: run::<day2::Day2>,
+        bench: bench::bench::<day2::Day2>,
+    },
+    Day {
+        input: "day3/input.txt",
+        run: run::<day3::Day3>,
+        bench: bench::bench::<day3::Day3>,
+    },
+    Day {
+        input: "day4/input.txt",
+        run: run::<day4::Day4>,
+        bench: bench::bench::<day4::Day4>,
+    },
+    Day {
+        input: "day5/input2.txt",
+        run: run::<day5::Day5>,
+        bench: bench::bench::<day5::Day5>,
+    },
+    Day {
+        input: "day6/input.txt",
+        run: run::<day6::Day6>,
+        bench: bench::bench::<day6::Day6>,
+    },
+    Day {
+        input: "day7/input.txt",
+        run: run::<day7::Day7>,
+        bench: bench::bench::<day7::Day7>,
+    },
+    Day {
+        input: "day8/input2.txt",
+        run: run::<day8::Day8>,
+        bench: bench::bench::<day8::Day8>,
+    },
+    Day {
+        input: "day9/input.txt",
+        run: run::<day9::Day9>,
+        bench: bench::bench::<day9::Day9>,
+    },
+    Day {
+        input: "day10/input2.txt",
+        run: run::<day10::Day10>,
+        bench: bench::bench::<day10::Day10>,
+    },
+    Day {
+        input: "day11/input2.txt",
+        run: run::<day11::Day11>,
+        bench: bench::bench::<day11::Day11>,
+    },
+    Day {
+        input: "day12/input2.txt",
+        run: run::<day12::Day12>,
+        bench: bench::bench::<day12::Day12>,
+    },
+    Day {
+        input: "day13/input2.txt",
+        run: run::<day13::Day13>,
+        bench: bench::bench::<day13::Day13>,
+    },
+];
+
+/// Answer of one part of a day, with the time it took to get it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// FNV-1a hash of the input, to tell which input an answer is for
+    pub input_hash: String,
+}
+
+pub fn run<S: Solution>(day: u8, input: &str, part: Option<u8>) -> Result<Vec<Record>, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_ns = start.elapsed().as_nanos() as u64;
+    let input_hash = input_hash(input);
+    let mut records = Vec::new();
+
+    let mut record = |part: u8, solve: &dyn Fn() -> String| {
+        let start = Instant::now();
+        let answer = solve();
+        records.push(Record {
+            day,
+            part,
+            answer,
+            parse_ns,
+            solve_ns: start.elapsed().as_nanos() as u64,
+            input_hash: input_hash.clone(),
+        });
+    };
+
+    if part.is_none() || part == Some(1) {
+        record(1, &|| S::part1(&parsed).to_string());
+    }
+    if part.is_none() || part == Some(2) {
+        record(2, &|| S::part2(&parsed).to_string());
+    }
+    Ok(records)
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and compiler versions.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Resolves a path relative to the workspace root, where each day's crate
+/// and the answer registry live.
+pub fn workspace_path(relative: &str) -> String {
+    format!("{}/../{}", env!("CARGO_MANIFEST_DIR"), relative)
+}
+
+pub fn input_path(day: &Day) -> String {
+    workspace_path(day.input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_run_records() {
+        let records = run::<day6::Day6>(6, "mjqjpqmgbljsphdztnvjfqwrcgsmlb", Some(2)).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!((records[0].day, records[0].part), (6, 2));
+        assert_eq!(records[0].answer, "19");
+        assert_eq!(
+            records[0].input_hash,
+            input_hash("mjqjpqmgbljsphdztnvjfqwrcgsmlb")
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use aoc::answers::Registry;
+use aoc::bench::{self, Baseline};
+use aoc::query::query;
+use aoc::{input_path, DAYS};
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{exit_with, read_input};
+use day4::index::parse_query;
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Output format; `json` prints one JSON record per line
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver of a given day
+    Run {
+        /// Day to run, from 1 to 13. All of them when omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=13))]
+        day: Option<u8>,
+        /// Only run this part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` to read from stdin. Defaults to the day's puzzle input
+        #[arg(long, requires = "day")]
+        input: Option<String>,
+    },
+    /// Time parsing and both parts of every day, or of a single day
+    Bench {
+        /// Day to benchmark, all of them when omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=13))]
+        day: Option<u8>,
+        /// Number of runs of each step
+        #[arg(long, default_value_t = 20)]
+        iterations: usize,
+        /// Compare against the results saved in this file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Allowed slowdown of the median against the baseline, in percent
+        #[arg(long, default_value_t = 10.0)]
+        tolerance: f64,
+        /// Save the results to this file, to be used as a later baseline
+        #[arg(long)]
+        save: Option<PathBuf>,
+    },
+    /// Check the solvers against the expected answers in the registry
+    Verify {
+        /// Only check this day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=13))]
+        day: Option<u8>,
+        /// Registry of expected answers. Defaults to the workspace's answers.toml
+        #[arg(long)]
+        answers: Option<String>,
+        /// Check the puzzle statement's examples of each day instead
+        #[arg(long, conflicts_with = "answers")]
+        examples: bool,
+    },
+    /// Find the elf pairs of day 4 assigned to a section or range of sections
+    Query {
+        /// Section (`57`) or range of sections (`10-20`)
+        sections: String,
+        /// Input file, `-` to read from stdin. Defaults to day 4's puzzle input
+        #[arg(long)]
+        input: Option<String>,
+    },
+}
+
+fn selected_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(d) => vec![d],
+        None => (1..=DAYS.len() as u8).collect(),
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) {
+    println!("{}", serde_json::to_string(value).unwrap());
+}
+
+fn run_benchmarks(
+    days: Vec<u8>,
+    iterations: usize,
+    baseline: Option<Baseline>,
+    tolerance: f64,
+    format: Format,
+) -> (Baseline, bool) {
+    let mut results = Baseline::default();
+    let mut regressed = false;
+
+    for day in days {
+        let solver = &DAYS[(day - 1) as usize];
+        let content = read_input(&input_path(solver))
+            .unwrap_or_else(|e| exit_with("can't read the input", e));
+        let result = (solver.bench)(day, &content, iterations.max(1))
+            .unwrap_or_else(|e| exit_with(&format!("invalid input for day {}", day), e));
+
+        if format == Format::Text {
+            println!("Day {}", day);
+        }
+        for (i, (step, stats)) in result.steps().into_iter().enumerate() {
+            let previous = baseline
+                .as_ref()
+                .and_then(|b| b.day(day))
+                .map(|b| b.steps()[i].1);
+            let is_regression = previous.is_some_and(|p| bench::is_regression(stats, p, tolerance));
+            regressed |= is_regression;
+            match previous {
+                _ if format == Format::Json => print_json(&json!({
+                    "day": day,
+                    "step": step,
+                    "stats": stats,
+                    "baseline": previous,
+                    "regression": is_regression,
+                })),
+                Some(previous) if is_regression => {
+                    println!(
+                        "  {:<7} {}  REGRESSION (baseline median {})",
+                        step,
+                        stats,
+                        bench::format_ns(previous.median_ns)
+                    );
+                }
+                _ => println!("  {:<7} {}", step, stats),
+            }
+        }
+        results.days.push(result);
+    }
+    (results, regressed)
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, part, input } => {
+            for day in selected_days(day) {
+                let solver = &DAYS[(day - 1) as usize];
+                let path = input.clone().unwrap_or_else(|| input_path(solver));
+                let content =
+                    read_input(&path).unwrap_or_else(|e| exit_with("can't read the input", e));
+
+                let records = (solver.run)(day, &content, part)
+                    .unwrap_or_else(|e| exit_with(&format!("invalid input for day {}", day), e));
+                for record in records {
+                    match cli.format {
+                        Format::Text => {
+                            println!("Day {} part {}: {}", day, record.part, record.answer)
+                        }
+                        Format::Json => print_json(&record),
+                    }
+                }
+            }
+        }
+        Command::Bench {
+            day,
+            iterations,
+            baseline,
+            tolerance,
+            save,
+        } => {
+            let days = selected_days(day);
+            let baseline = baseline.map(|path| {
+                let content = fs::read_to_string(path)
+                    .unwrap_or_else(|e| exit_with("can't read the baseline", e));
+                serde_json::from_str::<Baseline>(&content)
+                    .unwrap_or_else(|e| exit_with("invalid baseline", e))
+            });
+
+            let (results, regressed) =
+                run_benchmarks(days, iterations, baseline, tolerance, cli.format);
+            if let Some(path) = save {
+                let json = serde_json::to_string_pretty(&results).unwrap();
+                fs::write(path, json).unwrap_or_else(|e| exit_with("can't write the results", e));
+            }
+            if regressed {
+                process::exit(1);
+            }
+        }
+        Command::Verify {
+            day,
+            answers,
+            examples,
+        } => {
+            let path = answers.unwrap_or_else(Registry::default_path);
+            let registry = if examples {
+                Registry::examples()
+            } else {
+                Registry::load(&path)
+            };
+            let registry =
+                registry.unwrap_or_else(|e| exit_with("could not load the expected answers", e));
+
+            let checks = registry.verify(day);
+            let failed = checks.iter().filter(|c| !c.passed()).count();
+            match cli.format {
+                Format::Text => {
+                    checks.iter().for_each(|c| println!("{}", c));
+                    println!("{} checked, {} failed", checks.len(), failed);
+                }
+                Format::Json => checks.iter().for_each(|c| {
+                    print_json(&json!({
+                        "day": c.answer.day,
+                        "part": c.part,
+                        "input": c.answer.input,
+                        "expected": c.expected,
+                        "actual": c.actual.as_ref().ok(),
+                        "error": c.actual.as_ref().err(),
+                        "passed": c.passed(),
+                    }))
+                }),
+            }
+            if failed > 0 {
+                process::exit(1);
+            }
+        }
+        Command::Query { sections, input } => {
+            let sections = parse_query(&sections).unwrap_or_else(|e| exit_with("invalid query", e));
+            let path = input.unwrap_or_else(|| input_path(&DAYS[3]));
+            let content =
+                read_input(&path).unwrap_or_else(|e| exit_with("can't read the input", e));
+            let hits = query(&content, &sections)
+                .unwrap_or_else(|e| exit_with("invalid input for day 4", e));
+            for hit in hits {
+                match cli.format {
+                    Format::Text => println!("line {}: {}", hit.line, hit.pair),
+                    Format::Json => print_json(&hit),
+                }
+            }
+        }
+    }
+}
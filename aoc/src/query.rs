@@ -0,0 +1,49 @@
+use common::{ParseError, Solution};
+use day4::{Day4, IntervalIndex, Range};
+use serde::Serialize;
+
+/// Elf pair of day 4 assigned to some of the queried sections.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Hit {
+    pub line: usize,
+    pub pair: String,
+}
+
+/// Pairs of day 4's `input` with an assignment meeting `sections`.
+pub fn query(input: &str, sections: &Range<u64>) -> Result<Vec<Hit>, ParseError> {
+    let pairs = Day4::parse(input)?;
+    let lines: Vec<&str> = input.lines().collect();
+    Ok(IntervalIndex::of_pairs(&pairs)
+        .overlapping(sections)
+        .into_iter()
+        .map(|line| Hit {
+            line,
+            pair: lines[line - 1].to_string(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query() {
+        let input = "2-4,6-8\n2-3,4-5\n5-7,7-9";
+        let hits = query(input, &Range::new(7, 7)).unwrap();
+        assert_eq!(
+            hits,
+            [
+                Hit {
+                    line: 1,
+                    pair: "2-4,6-8".to_string()
+                },
+                Hit {
+                    line: 3,
+                    pair: "5-7,7-9".to_string()
+                }
+            ]
+        );
+        assert!(query("1-2,3", &Range::new(1, 1)).is_err());
+    }
+}
@@ -0,0 +1,30 @@
+use aoc::answers::Registry;
+use aoc::DAYS;
+
+fn registry() -> Registry {
+    Registry::load(&Registry::default_path()).expect("answers.toml should be valid")
+}
+
+#[test]
+fn every_day_has_answers() {
+    let registry = registry();
+    for day in 1..=DAYS.len() as u8 {
+        assert!(
+            registry.answers.iter().any(|a| a.day == day),
+            "no registered answer for day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn solvers_match_registered_answers() {
+    let registry = registry();
+    let failures: Vec<String> = registry
+        .verify(None)
+        .iter()
+        .filter(|c| !c.passed())
+        .map(|c| c.to_string())
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,29 @@
+use aoc::answers::Registry;
+use aoc::DAYS;
+
+fn examples() -> Registry {
+    Registry::examples().expect("every day should have valid example fixtures")
+}
+
+#[test]
+fn every_day_has_examples() {
+    let examples = examples();
+    for day in 1..=DAYS.len() as u8 {
+        assert!(
+            examples.answers.iter().any(|a| a.day == day),
+            "no example for day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn solvers_match_examples() {
+    let failures: Vec<String> = examples()
+        .verify(None)
+        .iter()
+        .filter(|c| !c.passed())
+        .map(|c| c.to_string())
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
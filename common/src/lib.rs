@@ -0,0 +1,48 @@
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+mod parse;
+
+pub use parse::{number, tokens, ParseError};
+
+/// A puzzle solver: the input is parsed once, then each part works on the
+/// parsed representation so they can be run, tested and timed separately.
+pub trait Solution {
+    type Parsed;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    fn part1(parsed: &Self::Parsed) -> Self::Answer1;
+    fn part2(parsed: &Self::Parsed) -> Self::Answer2;
+}
+
+/// Reads the puzzle input from `path`, or from stdin when `path` is `-`.
+pub fn read_input(path: &str) -> io::Result<String> {
+    if path == "-" {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content)?;
+        Ok(content)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Input path given as first command line argument, `default` otherwise.
+pub fn input_arg(default: &str) -> String {
+    env::args().nth(1).unwrap_or_else(|| default.to_string())
+}
+
+/// Prints `message` with what went wrong, then exits with a failure status.
+pub fn exit_with(message: &str, e: impl Display) -> ! {
+    eprintln!("{}: {}", message, e);
+    process::exit(1)
+}
+
+/// Parses `input` for a day binary, exiting with the diagnostic on failure.
+pub fn parse_or_exit<S: Solution>(input: &str) -> S::Parsed {
+    S::parse(input).unwrap_or_else(|e| exit_with("invalid input", e))
+}
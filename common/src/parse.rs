@@ -0,0 +1,152 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned by every puzzle parser. Lines and columns are 1-based;
+/// parsers working on a single line report line 1 and let the caller move
+/// the error to the right place with [`ParseError::offset`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// `found` was read where `expected` was required.
+    Unexpected {
+        line: usize,
+        column: usize,
+        expected: String,
+        found: String,
+    },
+    /// The input ended before `expected` could be read.
+    Missing {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+}
+
+impl ParseError {
+    pub fn unexpected(
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError::Unexpected {
+            line: 1,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn missing(column: usize, expected: impl Into<String>) -> Self {
+        ParseError::Missing {
+            line: 1,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::Unexpected { line, .. } | ParseError::Missing { line, .. } => *line,
+        }
+    }
+
+    pub fn column(&self) -> usize {
+        match self {
+            ParseError::Unexpected { column, .. } | ParseError::Missing { column, .. } => *column,
+        }
+    }
+
+    pub fn expected(&self) -> &str {
+        match self {
+            ParseError::Unexpected { expected, .. } | ParseError::Missing { expected, .. } => {
+                expected
+            }
+        }
+    }
+
+    /// Moves the error `lines` lines down and `columns` columns right, for
+    /// errors raised while parsing a slice of the input.
+    pub fn offset(mut self, lines: usize, columns: usize) -> Self {
+        match &mut self {
+            ParseError::Unexpected { line, column, .. }
+            | ParseError::Missing { line, column, .. } => {
+                *line += lines;
+                *column += columns;
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Unexpected {
+                line,
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}, column {}: expected {}, found `{}`",
+                line, column, expected, found
+            ),
+            ParseError::Missing {
+                line,
+                column,
+                expected,
+            } => write!(
+                f,
+                "line {}, column {}: expected {}, found end of input",
+                line, column, expected
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Splits `line` on whitespace, pairing each token with its column.
+pub fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count() + 1, token)
+    })
+}
+
+/// Parses `token`, found at `column`, as a number.
+pub fn number<T: FromStr>(column: usize, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::unexpected(column, "a number", token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let t: Vec<(usize, &str)> = tokens("move 1  from 2").collect();
+        assert_eq!(t, vec![(1, "move"), (6, "1"), (9, "from"), (14, "2")]);
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u32>(3, "42"), Ok(42));
+        let e = number::<u32>(3, "4a").unwrap_err();
+        assert_eq!(e.column(), 3);
+        assert_eq!(e.expected(), "a number");
+    }
+
+    #[test]
+    fn test_offset() {
+        let e = ParseError::missing(4, "a range").offset(9, 2);
+        assert_eq!(e.line(), 10);
+        assert_eq!(e.column(), 6);
+        assert_eq!(
+            e.to_string(),
+            "line 10, column 6: expected a range, found end of input"
+        );
+    }
+}
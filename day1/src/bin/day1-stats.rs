@@ -0,0 +1,24 @@
+use std::env;
+use std::fs::File;
+use std::io::BufWriter;
+
+use common::{input_arg, parse_or_exit, read_input};
+use day1::stats::{write_csv, Report};
+use day1::Day1;
+
+// usage: day1-stats [input] [per-elf csv output]
+fn main() {
+    let content =
+        read_input(&input_arg("input.txt")).expect("Should have been able to read the file");
+    let elves = parse_or_exit::<Day1>(&content);
+
+    match Report::new(&elves) {
+        Some(report) => print!("{}", report),
+        None => println!("no elf in the input"),
+    }
+
+    if let Some(path) = env::args().nth(2) {
+        let file = File::create(&path).expect("the csv file should be writable");
+        write_csv(&elves, BufWriter::new(file)).expect("the csv file should be writable");
+    }
+}
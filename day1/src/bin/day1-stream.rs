@@ -0,0 +1,29 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+
+use common::{exit_with, input_arg};
+use day1::stream::{top_n_parallel, top_n_streaming};
+
+// usage: day1-stream [input] [threads]
+// for inventories too large to be read at once, `-` reads from stdin
+fn main() {
+    let path = input_arg("input.txt");
+    let threads: usize = env::args().nth(2).map_or(1, |t| {
+        t.parse().expect("the thread count should be a number")
+    });
+
+    let top = if path == "-" {
+        top_n_streaming(io::stdin().lock(), 3)
+    } else if threads > 1 {
+        top_n_parallel(Path::new(&path), 3, threads)
+    } else {
+        let file = File::open(&path).expect("Should have been able to read the file");
+        top_n_streaming(BufReader::new(file), 3)
+    };
+    let top = top.unwrap_or_else(|e| exit_with("invalid input", e));
+
+    println!("{}", top.first().map_or(0, |&(_, total)| total));
+    println!("{}", top.iter().map(|&(_, total)| total).sum::<u64>());
+}
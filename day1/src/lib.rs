@@ -0,0 +1,176 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use common::{number, ParseError, Solution};
+
+pub mod stats;
+pub mod stream;
+
+pub struct Day1;
+
+/// Inventory of one elf.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// calories of all the items carried
+    pub total: u64,
+    pub items: usize,
+}
+
+/// Bounded min-heap keeping the `k` largest totals pushed into it.
+#[derive(Debug, Clone)]
+pub struct TopN {
+    k: usize,
+    // its root is the first to drop
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopN {
+    pub fn new(k: usize) -> Self {
+        TopN {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: usize, total: u64) {
+        self.heap.push(Reverse((total, Reverse(elf))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The totals kept, largest first, with the index of the elf carrying
+    /// them. Elves with the same total are ranked by index.
+    pub fn into_vec(self) -> Vec<(usize, u64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((total, Reverse(elf)))| (elf, total))
+            .collect()
+    }
+}
+
+/// The `k` largest totals, largest first, with the index of the elf
+/// carrying them. Elves with the same total are ranked by index.
+pub fn top_n(elves: &[Elf], k: usize) -> Vec<(usize, u64)> {
+    let mut top = TopN::new(k);
+    for (elf, &Elf { total, .. }) in elves.iter().enumerate() {
+        top.push(elf, total);
+    }
+    top.into_vec()
+}
+
+fn sum_of_top(elves: &[Elf], k: usize) -> u64 {
+    top_n(elves, k).iter().map(|&(_, total)| total).sum()
+}
+
+/// Groups the lines of an inventory into elves, one line at a time. Elves
+/// are separated by empty lines, anything else must be a number.
+#[derive(Debug, Default)]
+pub(crate) struct Grouper {
+    current: Option<Elf>,
+}
+
+impl Grouper {
+    /// Reads the line at index `i`, returning the elf it completes if any.
+    pub(crate) fn line(&mut self, i: usize, line: &str) -> Result<Option<Elf>, ParseError> {
+        if line.is_empty() {
+            return Ok(self.current.take());
+        }
+        let calories: u64 = number(1, line).map_err(|e| e.offset(i, 0))?;
+        let elf = self.current.get_or_insert_with(Elf::default);
+        elf.total = elf.total.checked_add(calories).ok_or_else(|| {
+            let expected = format!("at most {} calories", u64::MAX - elf.total);
+            ParseError::unexpected(1, expected, line).offset(i, 0)
+        })?;
+        elf.items += 1;
+        Ok(None)
+    }
+
+    /// The last elf, which isn't always followed by a blank line.
+    pub(crate) fn finish(self) -> Option<Elf> {
+        self.current
+    }
+}
+
+impl Solution for Day1 {
+    type Parsed = Vec<Elf>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut elves = Vec::new();
+        let mut grouper = Grouper::default();
+        for (i, line) in input.lines().enumerate() {
+            elves.extend(grouper.line(i, line)?);
+        }
+        elves.extend(grouper.finish());
+
+        Ok(elves)
+    }
+
+    fn part1(elves: &Self::Parsed) -> Self::Answer1 {
+        sum_of_top(elves, 1)
+    }
+
+    fn part2(elves: &Self::Parsed) -> Self::Answer2 {
+        sum_of_top(elves, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn totals(totals: &[u64]) -> Vec<Elf> {
+        totals
+            .iter()
+            .map(|&total| Elf { total, items: 1 })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_flushes_last_group() {
+        let elves = Day1::parse("1\n2\n\n3").unwrap();
+        assert_eq!(
+            elves,
+            vec![Elf { total: 3, items: 2 }, Elf { total: 3, items: 1 }]
+        );
+        assert_eq!(Day1::parse("1\n2\n\n3\n\n").unwrap(), elves);
+        assert_eq!(Day1::parse("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Day1::parse("1\n\n12a4\n3").err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(1, "a number", "12a4").offset(2, 0)
+        );
+        let e = Day1::parse("1\n \n3").err().unwrap();
+        assert_eq!(e.line(), 2);
+        assert!(Day1::parse("-5").is_err());
+
+        let elves = Day1::parse("4000000000\n4000000000").unwrap();
+        assert_eq!(elves[0].total, 8_000_000_000);
+        let elves = Day1::parse("18446744073709551614\n1").unwrap();
+        assert_eq!(elves[0].total, u64::MAX);
+        let e = Day1::parse("5\n\n18446744073709551615\n1").err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(1, "at most 0 calories", "1").offset(3, 0)
+        );
+    }
+
+    #[test]
+    fn test_top_n() {
+        let elves = totals(&[6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(top_n(&elves, 3), vec![(3, 24000), (2, 11000), (4, 10000)]);
+        assert_eq!(top_n(&elves, 0), vec![]);
+        assert_eq!(top_n(&elves, 10).len(), 5);
+        assert_eq!(
+            top_n(&totals(&[5, 7, 5, 5]), 3),
+            vec![(1, 7), (0, 5), (2, 5)]
+        );
+    }
+}
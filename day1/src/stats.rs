@@ -0,0 +1,154 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::Elf;
+
+const PERCENTILES: [u8; 5] = [10, 25, 50, 75, 90];
+const HISTOGRAM_BUCKETS: usize = 10;
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Summary of the calories carried by the whole expedition.
+#[derive(Debug, PartialEq)]
+pub struct Report {
+    pub elves: usize,
+    pub items: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// nearest-rank percentiles of the totals, as `(percentile, total)`
+    pub percentiles: Vec<(u8, u64)>,
+    /// number of elves per bucket, as `(lowest total, highest total, elves)`
+    pub histogram: Vec<(u64, u64, usize)>,
+}
+
+impl Report {
+    /// `None` when there is no elf to describe.
+    pub fn new(elves: &[Elf]) -> Option<Self> {
+        if elves.is_empty() {
+            return None;
+        }
+
+        let mut totals: Vec<u64> = elves.iter().map(|e| e.total).collect();
+        totals.sort_unstable();
+        let n = totals.len();
+
+        let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / n as f64;
+        let median = if n.is_multiple_of(2) {
+            (totals[n / 2 - 1] as f64 + totals[n / 2] as f64) / 2.0
+        } else {
+            totals[n / 2] as f64
+        };
+        let percentiles = PERCENTILES
+            .iter()
+            .map(|&p| {
+                let rank = (p as usize * n).div_ceil(100).max(1);
+                (p, totals[rank - 1])
+            })
+            .collect();
+
+        Some(Report {
+            elves: n,
+            items: elves.iter().map(|e| e.items).sum(),
+            mean,
+            median,
+            percentiles,
+            histogram: histogram(&totals),
+        })
+    }
+}
+
+/// Splits the sorted `totals` into buckets of equal width.
+fn histogram(totals: &[u64]) -> Vec<(u64, u64, usize)> {
+    let (min, max) = (totals[0], totals[totals.len() - 1]);
+    let width = (max - min) / HISTOGRAM_BUCKETS as u64 + 1;
+    let mut buckets: Vec<(u64, u64, usize)> = (0..HISTOGRAM_BUCKETS as u64)
+        .map(|i| {
+            let low = min + i * width;
+            (low, (low + width - 1).min(max), 0)
+        })
+        .take_while(|&(low, _, _)| low <= max)
+        .collect();
+
+    for &total in totals {
+        buckets[((total - min) / width) as usize].2 += 1;
+    }
+    buckets
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "elves:  {}", self.elves)?;
+        writeln!(f, "items:  {}", self.items)?;
+        writeln!(f, "mean:   {:.1}", self.mean)?;
+        writeln!(f, "median: {:.1}", self.median)?;
+        for (p, total) in &self.percentiles {
+            writeln!(f, "p{:<5} {}", p, total)?;
+        }
+
+        let largest = self.histogram.iter().map(|b| b.2).max().unwrap_or(0).max(1);
+        for &(low, high, count) in &self.histogram {
+            let bar = "#".repeat((count * HISTOGRAM_WIDTH).div_ceil(largest));
+            writeln!(
+                f,
+                "{:>7}-{:<7} {:<w$} {}",
+                low,
+                high,
+                bar,
+                count,
+                w = HISTOGRAM_WIDTH
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes one `elf,items,total` row per elf, after a header.
+pub fn write_csv<W: Write>(elves: &[Elf], mut out: W) -> io::Result<()> {
+    writeln!(out, "elf,items,total")?;
+    for (i, elf) in elves.iter().enumerate() {
+        writeln!(out, "{},{},{}", i, elf.items, elf.total)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elves(totals: &[u64]) -> Vec<Elf> {
+        totals
+            .iter()
+            .map(|&total| Elf { total, items: 2 })
+            .collect()
+    }
+
+    #[test]
+    fn test_report() {
+        let report = Report::new(&elves(&[6000, 4000, 11000, 24000, 10000])).unwrap();
+        assert_eq!((report.elves, report.items), (5, 10));
+        assert_eq!(report.mean, 11000.0);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(report.percentiles[0], (10, 4000));
+        assert_eq!(report.percentiles[4], (90, 24000));
+        assert_eq!(report.histogram.len(), 10);
+        assert_eq!(report.histogram[0], (4000, 6000, 2));
+        assert_eq!(report.histogram.iter().map(|b| b.2).sum::<usize>(), 5);
+
+        assert_eq!(Report::new(&elves(&[1, 4])).unwrap().median, 2.5);
+        assert_eq!(Report::new(&[]), None);
+    }
+
+    #[test]
+    fn test_histogram_single_value() {
+        assert_eq!(histogram(&[7, 7, 7]), vec![(7, 7, 3)]);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = Vec::new();
+        write_csv(&elves(&[3, 5]), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "elf,items,total\n0,2,3\n1,2,5\n"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::thread;
+
+use common::ParseError;
+
+use crate::{Grouper, TopN};
+
+/// Error raised while reading an inventory that isn't held in memory.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// What was found reading a whole inventory, or a chunk of one.
+struct Scan {
+    top: TopN,
+    elves: usize,
+    lines: usize,
+}
+
+fn scan<R: BufRead>(mut reader: R, k: usize) -> Result<Scan, StreamError> {
+    let mut grouper = Grouper::default();
+    let mut top = TopN::new(k);
+    let mut elves = 0;
+    let mut lines = 0;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let content = line.strip_suffix('\n').unwrap_or(&line);
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        if let Some(elf) = grouper.line(lines, content)? {
+            top.push(elves, elf.total);
+            elves += 1;
+        }
+        lines += 1;
+    }
+    if let Some(elf) = grouper.finish() {
+        top.push(elves, elf.total);
+        elves += 1;
+    }
+
+    Ok(Scan { top, elves, lines })
+}
+
+/// Same as [`crate::top_n`] on the inventory read from `reader`, keeping a
+/// single line of it in memory at a time.
+pub fn top_n_streaming<R: BufRead>(reader: R, k: usize) -> Result<Vec<(usize, u64)>, StreamError> {
+    Ok(scan(reader, k)?.top.into_vec())
+}
+
+/// Same as [`top_n_streaming`] on the file at `path`, split on empty lines
+/// into `threads` chunks read in parallel.
+pub fn top_n_parallel(
+    path: &Path,
+    k: usize,
+    threads: usize,
+) -> Result<Vec<(usize, u64)>, StreamError> {
+    let threads = threads.max(1) as u64;
+    let len = File::open(path)?.metadata()?.len();
+    let mut bounds = vec![0];
+    for i in 1..threads {
+        let start = boundary_after(path, len * i / threads)?;
+        bounds.push(start.max(bounds[bounds.len() - 1]));
+    }
+    bounds.push(len);
+
+    let scans: Vec<Result<Scan, StreamError>> = thread::scope(|s| {
+        let handles: Vec<_> = bounds
+            .windows(2)
+            .map(|w| {
+                let (start, end) = (w[0], w[1]);
+                s.spawn(move || {
+                    let mut file = File::open(path)?;
+                    file.seek(SeekFrom::Start(start))?;
+                    scan(BufReader::new(file.take(end - start)), k)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("a chunk should be read without panicking"))
+            .collect()
+    });
+
+    // chunks number their elves and lines from 0, shift them into place
+    let mut top = TopN::new(k);
+    let (mut elves, mut lines) = (0, 0);
+    for scan in scans {
+        let scan = scan.map_err(|e| match e {
+            StreamError::Parse(e) => StreamError::Parse(e.offset(lines, 0)),
+            e => e,
+        })?;
+        for (elf, total) in scan.top.into_vec() {
+            top.push(elves + elf, total);
+        }
+        elves += scan.elves;
+        lines += scan.lines;
+    }
+    Ok(top.into_vec())
+}
+
+/// Offset of the first line at or after `offset` that follows an empty
+/// line, so that no elf starts before it and ends after it.
+fn boundary_after(path: &Path, offset: u64) -> io::Result<u64> {
+    if offset == 0 {
+        return Ok(0);
+    }
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(offset - 1))?;
+    let mut reader = BufReader::new(file);
+    let mut line = Vec::new();
+
+    // skip the end of the line `offset - 1` is on
+    let mut position = offset - 1 + reader.read_until(b'\n', &mut line)? as u64;
+    loop {
+        line.clear();
+        let read = reader.read_until(b'\n', &mut line)?;
+        position += read as u64;
+        if read == 0 || line == b"\n" || line == b"\r\n" {
+            return Ok(position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::Cursor;
+
+    use common::Solution;
+
+    use super::*;
+    use crate::{top_n, Day1};
+
+    /// Inventory of `elves` elves with pseudo-random items, some of them
+    /// separated by several empty lines.
+    fn inventory(elves: usize) -> String {
+        let mut seed: u64 = 42;
+        let mut next = move || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+            seed >> 33
+        };
+        let mut input = String::new();
+        for _ in 0..elves {
+            for _ in 0..=next() % 6 {
+                input.push_str(&format!("{}\n", next() % 10000));
+            }
+            input.push_str(if next() % 5 == 0 { "\n\n" } else { "\n" });
+        }
+        input
+    }
+
+    fn temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = env::temp_dir().join(format!("day1-{}-{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_streaming_matches_sequential() {
+        let input = inventory(500);
+        let expected = top_n(&Day1::parse(&input).unwrap(), 5);
+        let cursor = Cursor::new(input.trim_end());
+        assert_eq!(top_n_streaming(cursor, 5).unwrap(), expected);
+
+        let e = top_n_streaming(Cursor::new("1\r\n\r\n2x\r\n"), 3)
+            .err()
+            .unwrap();
+        assert_eq!(
+            e.to_string(),
+            Day1::parse("1\n\n2x\n").err().unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let input = inventory(500);
+        let expected = top_n(&Day1::parse(&input).unwrap(), 5);
+        let path = temp_file("parallel", &input);
+        for threads in [1, 2, 3, 8, 64] {
+            assert_eq!(top_n_parallel(&path, 5, threads).unwrap(), expected);
+        }
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_parallel_error_line() {
+        let mut input = inventory(200);
+        input.push_str("12a4\n");
+        input.push_str(&inventory(200));
+        let expected = Day1::parse(&input).err().unwrap();
+        let path = temp_file("error", &input);
+        match top_n_parallel(&path, 3, 4) {
+            Err(StreamError::Parse(e)) => assert_eq!(e, expected),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Display;
+
+use common::{number, tokens, ParseError, Solution};
+use geometry::Point;
+use grid::Grid;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Noop,
+    Addx(i32),
+}
+
+impl TryFrom<&str> for Command {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut split = tokens(value);
+        let end = value.len() + 1;
+        match split.next() {
+            Some((_, "noop")) => Ok(Command::Noop),
+            Some((_, "addx")) => match split.next() {
+                Some((col, x)) => Ok(Command::Addx(number(col, x)?)),
+                None => Err(ParseError::missing(end, "a number")),
+            },
+            Some((col, c)) => Err(ParseError::unexpected(col, "`noop` or `addx`", c)),
+            None => Err(ParseError::missing(end, "`noop` or `addx`")),
+        }
+    }
+}
+
+pub struct Screen {
+    sprite: i32,
+    pixels: Grid<char>,
+}
+
+impl Screen {
+    fn new() -> Self {
+        Self {
+            sprite: 0,
+            pixels: Grid::new(40, 6, ' '),
+        }
+    }
+
+    fn move_sprite(&mut self, pos: i32) {
+        self.sprite = pos;
+    }
+
+    fn visible(&self) -> (i32, i32, i32) {
+        (self.sprite - 1, self.sprite, self.sprite + 1)
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pixels)
+    }
+}
+
+fn run_cycles(commands: &[Command]) -> Vec<i32> {
+    let mut cycles: Vec<i32> = vec![];
+    let mut current_value = 1;
+
+    for c in commands {
+        match c {
+            Command::Noop => {
+                cycles.push(current_value);
+            }
+            Command::Addx(x) => {
+                cycles.push(current_value);
+                cycles.push(current_value);
+                current_value += x;
+            }
+        }
+    }
+    cycles
+}
+
+// cycles drawing the whole screen, which also covers the signal strengths
+const CYCLES: usize = 240;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = Screen;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let commands: Vec<Command> = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Command::try_from(l).map_err(|e| e.offset(i, 0)))
+            .collect::<Result<_, _>>()?;
+        let cycles = run_cycles(&commands).len();
+        if cycles < CYCLES {
+            let expected = format!("{} more cycles of instructions", CYCLES - cycles);
+            return Err(ParseError::missing(1, expected).offset(input.lines().count(), 0));
+        }
+        Ok(commands)
+    }
+
+    fn part1(commands: &Self::Parsed) -> Self::Answer1 {
+        let cycles = run_cycles(commands);
+        let cycle_num: [i32; 6] = [20, 60, 100, 140, 180, 220];
+
+        cycle_num
+            .iter()
+            .map(|&x| cycles[(x - 1) as usize] * x)
+            .sum()
+    }
+
+    fn part2(commands: &Self::Parsed) -> Self::Answer2 {
+        let cycles = run_cycles(commands);
+        let mut screen = Screen::new();
+
+        for i in 0..CYCLES / 40 {
+            for j in 0..40 {
+                screen.move_sprite(cycles[i * 40 + j]);
+                let (x1, x2, x3) = screen.visible();
+
+                if j as i32 == x1 || j as i32 == x2 || j as i32 == x3 {
+                    screen.pixels[Point::new(j, i)] = '#';
+                } else {
+                    screen.pixels[Point::new(j, i)] = '.';
+                }
+            }
+        }
+        screen
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_command() {
+        assert_eq!(Command::try_from("noop"), Ok(Command::Noop));
+        assert_eq!(Command::try_from("addx 1"), Ok(Command::Addx(1)));
+        assert_eq!(
+            Command::try_from("addx"),
+            Err(ParseError::missing(5, "a number"))
+        );
+        assert_eq!(
+            Command::try_from("mulx 2"),
+            Err(ParseError::unexpected(1, "`noop` or `addx`", "mulx"))
+        );
+    }
+
+    #[test]
+    fn test_too_few_cycles() {
+        assert_eq!(
+            Day10::parse("noop").err(),
+            Some(ParseError::missing(1, "239 more cycles of instructions").offset(1, 0))
+        );
+        let program = "addx 1\n".repeat(119) + "noop";
+        assert!(Day10::parse(&program).is_err());
+        let commands = Day10::parse(&(program + "\nnoop")).unwrap();
+        assert_eq!(
+            Day10::part1(&commands),
+            [20, 60, 100, 140, 180, 220]
+                .iter()
+                .map(|&c| c * (c / 2))
+                .sum::<i32>()
+        );
+    }
+}
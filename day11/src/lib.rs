@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::rc::Rc;
+
+use common::{number, ParseError, Solution};
+
+use pest::error::{ErrorVariant, LineColLocation};
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::*;
+
+#[derive(Parser)]
+#[grammar = "monkeys.pest"] // relative to src
+struct MonkeyParser;
+
+#[derive(Clone)]
+pub struct Monkey {
+    id: u64,
+    worry_levels: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+    target_true: u64,
+    target_false: u64,
+    divider: u64,
+    count: u64,
+}
+
+impl Display for Monkey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Monkey {}:", self.id)?;
+        for i in 0..self.worry_levels.len() {
+            write!(f, " {}", self.worry_levels[i]).unwrap();
+            if i < self.worry_levels.len() - 1 {
+                write!(f, ",").unwrap();
+            }
+        }
+        writeln!(f)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl TryFrom<&str> for Op {
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "+" => Ok(Op::Add),
+            "*" => Ok(Op::Mul),
+            _ => Err(ParseError::unexpected(1, "`+` or `*`", value)),
+        }
+    }
+}
+impl Op {
+    fn run(self, a: u64, b: u64) -> u64 {
+        match self {
+            Op::Mul => a * b,
+            Op::Add => a + b,
+        }
+    }
+}
+
+type Operation = Rc<dyn Fn(u64) -> u64>;
+type Test = Rc<dyn Fn(u64) -> bool>;
+
+// moves an error raised on the text of `pair` to the pair's position in the input
+fn at_pair(e: ParseError, pair: &Pair<Rule>) -> ParseError {
+    let (line, column) = pair.line_col();
+    e.offset(line - 1, column - 1)
+}
+
+fn number_of(pair: Pair<Rule>) -> Result<u64, ParseError> {
+    number(1, pair.as_str()).map_err(|e| at_pair(e, &pair))
+}
+
+fn from_pest(e: pest::error::Error<Rule>) -> ParseError {
+    let (line, column) = match e.line_col {
+        LineColLocation::Pos(pos) => pos,
+        LineColLocation::Span(start, _) => start,
+    };
+    let expected = match &e.variant {
+        ErrorVariant::ParsingError { positives, .. } => positives
+            .iter()
+            .map(|r| format!("{:?}", r))
+            .collect::<Vec<_>>()
+            .join(" or "),
+        ErrorVariant::CustomError { message } => message.clone(),
+    };
+    let found: String = e.line().chars().skip(column - 1).collect();
+    let err = if found.trim().is_empty() {
+        ParseError::missing(column, expected)
+    } else {
+        ParseError::unexpected(column, expected, found)
+    };
+    err.offset(line - 1, 0)
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys_rule = MonkeyParser::parse(Rule::monkeys, input)
+        .map_err(from_pest)?
+        .next()
+        .unwrap();
+
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut targets = Vec::new();
+
+    for monkey in monkeys_rule.into_inner() {
+        match monkey.as_rule() {
+            Rule::monkey => {
+                let mut monkey_rules = monkey.into_inner();
+                let monkey_id =
+                    number_of(monkey_rules.next().unwrap().into_inner().next().unwrap())?;
+
+                let mut starting_items: Vec<u64> = Vec::new();
+                let starting_rule = monkey_rules.next().unwrap();
+                for start in starting_rule.into_inner() {
+                    starting_items.push(number_of(start)?);
+                }
+
+                let mut operation_rule_inner = monkey_rules.next().unwrap().into_inner();
+                operation_rule_inner.next().unwrap();
+                let op_rule = operation_rule_inner.next().unwrap();
+                let op = Op::try_from(op_rule.as_str()).map_err(|e| at_pair(e, &op_rule))?;
+                let second_term = operation_rule_inner.next().unwrap();
+
+                let operation: Operation = match second_term.as_rule() {
+                    Rule::second_term => {
+                        let s = number_of(second_term)?;
+                        Rc::new(move |old: u64| -> u64 { op.run(old, s) })
+                    }
+                    Rule::old => Rc::new(move |old: u64| -> u64 { op.run(old, old) }),
+                    _ => unreachable!(),
+                };
+
+                let test_rule = monkey_rules.next().unwrap();
+                let second_test_term = number_of(test_rule.into_inner().next().unwrap())?;
+                let test_operation: Test =
+                    Rc::new(move |val: u64| -> bool { val.is_multiple_of(second_test_term) });
+
+                let target_true = monkey_rules.next().unwrap().into_inner().next().unwrap();
+                let target_false = monkey_rules.next().unwrap().into_inner().next().unwrap();
+
+                monkeys.push(Monkey {
+                    id: monkey_id,
+                    worry_levels: starting_items.into(),
+                    target_false: number_of(target_false.clone())?,
+                    target_true: number_of(target_true.clone())?,
+                    test: test_operation,
+                    operation,
+                    count: 0,
+                    divider: second_test_term,
+                });
+                targets.push(target_true);
+                targets.push(target_false);
+            }
+            Rule::EOI => (),
+            _ => unreachable!(),
+        }
+    }
+
+    // monkeys can only throw to monkeys that exist
+    for target in targets {
+        if number_of(target.clone())? >= monkeys.len() as u64 {
+            let e = ParseError::unexpected(
+                1,
+                format!("a monkey below {}", monkeys.len()),
+                target.as_str(),
+            );
+            return Err(at_pair(e, &target));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: bool) -> u64 {
+    let mut monkeys = monkeys.to_vec();
+
+    // take the the product of all dividers as it's the common divisor of all the monkeys
+    let product = monkeys.iter().fold(1, |acc, m| acc * m.divider);
+
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            while let Some(front) = monkeys[i].worry_levels.pop_front() {
+                monkeys[i].count += 1;
+                let new = if relief {
+                    (monkeys[i].operation)(front) / 3
+                } else {
+                    (monkeys[i].operation)(front) % product
+                };
+                let test_res = (monkeys[i].test)(new);
+                let target = if test_res {
+                    monkeys[i].target_true
+                } else {
+                    monkeys[i].target_false
+                } as usize;
+                monkeys[target].worry_levels.push_back(new);
+            }
+        }
+    }
+
+    let mut counts: Vec<u64> = monkeys.iter().map(|m| m.count).collect();
+    counts.sort();
+    counts[counts.len() - 1] * counts[counts.len() - 2]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<Monkey>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_monkeys(input)
+    }
+
+    fn part1(monkeys: &Self::Parsed) -> Self::Answer1 {
+        monkey_business(monkeys, 20, true)
+    }
+
+    fn part2(monkeys: &Self::Parsed) -> Self::Answer2 {
+        monkey_business(monkeys, 10000, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONKEY: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+    #[test]
+    fn test_parse() {
+        let monkeys = Day11::parse(MONKEY).unwrap();
+        assert_eq!(monkeys.len(), 1);
+        assert_eq!(monkeys[0].worry_levels, vec![79, 98]);
+        assert_eq!((monkeys[0].operation)(2), 38);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let e = Day11::parse(&MONKEY.replace("by 23", "by x"))
+            .err()
+            .unwrap();
+        assert_eq!((e.line(), e.column()), (4, 22));
+
+        let e = Day11::parse(
+            &MONKEY.replace("If true: throw to monkey 0", "If true: throw to monkey 1"),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(30, "a monkey below 1", "1").offset(4, 0)
+        );
+    }
+}
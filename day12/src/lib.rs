@@ -0,0 +1,153 @@
+use std::collections::{HashSet, VecDeque};
+
+use common::{ParseError, Solution};
+use geometry::Point;
+use grid::Grid;
+
+pub struct Heightmap {
+    grid: Grid<i64>,
+}
+
+impl TryFrom<&str> for Heightmap {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(
+            input,
+            "an elevation between `a` and `z`, `S` or `E`",
+            |c| match c {
+                'S' => Some((b'a' - 1) as i64),
+                'a'..='z' => Some(c as i64),
+                'E' => Some((b'z' + 1) as i64),
+                _ => None,
+            },
+        )?;
+
+        for (mark, expected) in [(b'a' - 1, "the start `S`"), (b'z' + 1, "the end `E`")] {
+            if !grid.iter().any(|(_, &h)| h == mark as i64) {
+                return Err(ParseError::missing(1, expected).offset(grid.height(), 0));
+            }
+        }
+
+        let map = Heightmap { grid };
+        // the parts climb from the start, and from any `a` as well
+        if find_shortest_path(&map, 'S').is_empty() {
+            let (y, line) = input
+                .lines()
+                .enumerate()
+                .find(|(_, line)| line.contains('E'))
+                .unwrap();
+            let col = line.chars().position(|c| c == 'E').unwrap() + 1;
+            return Err(ParseError::unexpected(
+                col,
+                "an end `E` reachable from the start `S`",
+                "E",
+            )
+            .offset(y, 0));
+        }
+        Ok(map)
+    }
+}
+
+fn find_shortest_path(map: &Heightmap, starting_char: char) -> Vec<u64> {
+    let grid = &map.grid;
+    let starting_points: Vec<Point<usize>> = grid
+        .iter()
+        .filter(|&(_, &h)| h == (b'a' - 1) as i64 || h == starting_char as i64)
+        .map(|(pos, _)| pos)
+        .collect();
+
+    let mut depths: Vec<u64> = vec![];
+
+    for starting_point in starting_points {
+        let mut queue: VecDeque<Point<usize>> = VecDeque::new();
+        queue.push_back(starting_point);
+        let mut visited = HashSet::new();
+        let mut current_depth: usize = 0;
+        let mut finished = false;
+
+        while !queue.is_empty() && !finished {
+            let mut level_size: usize = queue.len();
+
+            while level_size > 0 {
+                level_size -= 1;
+
+                let current_point = queue.pop_front().unwrap();
+                let current_char = grid[current_point];
+
+                if current_char == (b'z' + 1) as i64 {
+                    depths.push(current_depth as u64);
+                    finished = true;
+                } else {
+                    visited.insert(current_point);
+
+                    for new_point in grid.neighbours4(current_point) {
+                        if grid[new_point] - current_char <= 1
+                            && !visited.contains(&new_point)
+                            && !queue.contains(&new_point)
+                        {
+                            queue.push_back(new_point);
+                        }
+                    }
+                }
+            }
+
+            current_depth += 1;
+        }
+    }
+
+    depths
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Heightmap;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Heightmap::try_from(input)
+    }
+
+    fn part1(map: &Self::Parsed) -> Self::Answer1 {
+        *find_shortest_path(map, 'S').iter().min().unwrap()
+    }
+
+    fn part2(map: &Self::Parsed) -> Self::Answer2 {
+        *find_shortest_path(map, 'a').iter().min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    const INPUT: &str = "Sabqponm
+        abcryxxl
+        accszExk
+        acctuvwj
+        abdefghi";
+
+    #[test]
+    fn it_works() {
+        let output = find_shortest_path(&Heightmap::try_from(INPUT).unwrap(), 'S');
+        assert_eq!(output, vec![31]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Heightmap::try_from("Sab\nc1E").err().unwrap();
+        assert_eq!((e.line(), e.column()), (2, 2));
+        let e = Heightmap::try_from("Sab\ncdz").err().unwrap();
+        assert_eq!(e, ParseError::missing(1, "the end `E`").offset(2, 0));
+        let e = Heightmap::try_from("SzE").err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(3, "an end `E` reachable from the start `S`", "E")
+        );
+        let e = Heightmap::try_from("Sab\n  ccc\n  cEz").err().unwrap();
+        assert_eq!((e.line(), e.column()), (3, 4));
+    }
+}
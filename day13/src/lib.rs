@@ -0,0 +1,153 @@
+use serde::Deserialize;
+use std::cmp::Ordering;
+
+use common::{ParseError, Solution};
+
+#[derive(Deserialize, PartialEq, Eq, Clone)]
+#[serde(untagged)]
+pub enum Element {
+    Integer(u32),
+    List(Vec<Element>),
+}
+
+impl Element {
+    fn with_slice<T>(&self, f: impl FnOnce(&[Element]) -> T) -> T {
+        match self {
+            Self::List(n) => f(&n[..]),
+            Self::Integer(n) => f(&[Self::Integer(*n)]),
+        }
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Element::Integer(a), Element::Integer(b)) => a.cmp(b),
+            (l, r) => l.with_slice(|l| {
+                r.with_slice(|r| {
+                    l.iter()
+                        .zip(r.iter())
+                        .map(|(a, b)| a.cmp(b))
+                        // return the first ordering that isn't `Equal`
+                        .find(|&ord| ord != Ordering::Equal)
+                        // or compare the lengths
+                        .unwrap_or_else(|| l.len().cmp(&r.len()))
+                })
+            }),
+        }
+    }
+}
+
+impl std::fmt::Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::List(list) => f.debug_list().entries(list).finish(),
+        }
+    }
+}
+
+fn parse_packet(line: &str) -> Result<Element, ParseError> {
+    serde_json::from_str::<Element>(line).map_err(|e| {
+        let column = e.column().max(1);
+        let found: String = line.chars().skip(column - 1).collect();
+        ParseError::unexpected(column, "a packet", found).offset(e.line() - 1, 0)
+    })
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<(Element, Element)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let mut packets = input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| parse_packet(line).map_err(|e| e.offset(i, 0)));
+
+        let mut pairs = Vec::new();
+        while let Some(left) = packets.next() {
+            let right = packets.next().unwrap_or_else(|| {
+                Err(ParseError::missing(1, "a second packet").offset(input.lines().count(), 0))
+            });
+            pairs.push((left?, right?));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Self::Answer1 {
+        pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(i, _)| i + 1)
+            .sum()
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Self::Answer2 {
+        let packet_2 = Element::List(vec![Element::List(vec![Element::Integer(2)])]);
+        let packet_6 = Element::List(vec![Element::List(vec![Element::Integer(6)])]);
+        let mut packets: Vec<&Element> = pairs
+            .iter()
+            .flat_map(|(left, right)| [left, right])
+            .collect();
+
+        packets.push(&packet_2);
+        packets.push(&packet_6);
+        packets.sort();
+
+        let position_2 = packets.iter().position(|p| **p == packet_2).unwrap() + 1;
+        let position_6 = packets.iter().position(|p| **p == packet_6).unwrap() + 1;
+        position_6 * position_2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        let l1 = Element::Integer(10);
+        let l2 = Element::Integer(2);
+        let l3 = Element::Integer(2);
+
+        assert_eq!(l1.partial_cmp(&l2), Some(Ordering::Greater));
+        assert_eq!(l2.partial_cmp(&l1), Some(Ordering::Less));
+        assert_eq!(l2.partial_cmp(&l3), Some(Ordering::Equal));
+
+        let l1 = Element::List(vec![]);
+        let l2 = Element::List(vec![Element::Integer(1)]);
+        assert_eq!(l1.partial_cmp(&l2), Some(Ordering::Less));
+        assert_eq!(l2.partial_cmp(&l1), Some(Ordering::Greater));
+
+        let l1 = Element::Integer(1);
+        let l2 = Element::List(vec![Element::Integer(2)]);
+        assert_eq!(l1.partial_cmp(&l2), Some(Ordering::Less));
+        assert_eq!(l2.partial_cmp(&l1), Some(Ordering::Greater));
+
+        let l1 = Element::List(vec![Element::Integer(1)]);
+        let l2 = Element::List(vec![Element::Integer(2)]);
+        assert_eq!(l1.partial_cmp(&l2), Some(Ordering::Less));
+        assert_eq!(l2.partial_cmp(&l1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day13::parse("[1,2]\n[3]\n\n[1,x]\n[]").err().unwrap();
+        assert_eq!(e, ParseError::unexpected(4, "a packet", "x]").offset(3, 0));
+        let e = Day13::parse("[1]").err().unwrap();
+        assert_eq!(e, ParseError::missing(1, "a second packet").offset(1, 0));
+    }
+}
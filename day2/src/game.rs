@@ -0,0 +1,351 @@
+use std::str::FromStr;
+
+use common::{tokens, ParseError};
+
+/// A weapon, by its position in the list of the game's weapons.
+pub type Weapon = usize;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum WinLoseDraw {
+    Win = 6,
+    Lose = 0,
+    Draw = 3,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct WeaponInfo {
+    name: String,
+    // letters standing for it in the first and second columns of the guide
+    theirs: String,
+    mine: String,
+}
+
+/// Rules of a hand game: its weapons and which one beats which. A weapon is
+/// worth its position in the list, starting at 1.
+///
+/// Games are described one rule per line, either a weapon with the letters
+/// standing for it in each column (`Rock A X`), or a win (`Paper beats Rock`).
+/// Every pair of weapons must have a winner and every weapon must be able to
+/// win and lose.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Game {
+    weapons: Vec<WeaponInfo>,
+    // beats[a][b] when a beats b
+    beats: Vec<Vec<bool>>,
+}
+
+pub const ROCK_PAPER_SCISSORS: &str = "\
+Rock A X
+Paper B Y
+Scissors C Z
+Rock beats Scissors
+Paper beats Rock
+Scissors beats Paper";
+
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "\
+Rock A V
+Paper B W
+Scissors C X
+Lizard D Y
+Spock E Z
+Scissors beats Paper
+Paper beats Rock
+Rock beats Lizard
+Lizard beats Spock
+Spock beats Scissors
+Scissors beats Lizard
+Lizard beats Paper
+Paper beats Spock
+Spock beats Rock
+Rock beats Scissors";
+
+impl Game {
+    /// The game of the puzzle.
+    pub fn classic() -> Self {
+        ROCK_PAPER_SCISSORS
+            .parse()
+            .expect("the classic rules should be valid")
+    }
+
+    /// A game of `names.len()` weapons, which must be odd, where each
+    /// weapon beats the half of the others preceding it in the cycle.
+    ///
+    /// Panics if the number of weapons is even, or if there isn't a letter
+    /// of each column for every weapon.
+    pub fn cyclic(names: &[&str], theirs: &[&str], mine: &[&str]) -> Self {
+        let n = names.len();
+        assert!(n % 2 == 1, "a cyclic game needs an odd number of weapons");
+        assert!(
+            theirs.len() == n && mine.len() == n,
+            "every weapon needs a letter in each column"
+        );
+        let weapons = (0..n)
+            .map(|i| WeaponInfo {
+                name: names[i].to_string(),
+                theirs: theirs[i].to_string(),
+                mine: mine[i].to_string(),
+            })
+            .collect();
+        let beats = (0..n)
+            .map(|a| {
+                (0..n)
+                    .map(|b| (1..=n / 2).any(|k| (a + n - k) % n == b))
+                    .collect()
+            })
+            .collect();
+        Game { weapons, beats }
+    }
+
+    pub fn len(&self) -> usize {
+        self.weapons.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weapons.is_empty()
+    }
+
+    pub fn name(&self, weapon: Weapon) -> &str {
+        &self.weapons[weapon].name
+    }
+
+    /// Letter standing for `weapon` in the second column.
+    pub fn letter(&self, weapon: Weapon) -> &str {
+        &self.weapons[weapon].mine
+    }
+
+    /// Letter standing for `weapon` in the first column.
+    pub fn their_letter(&self, weapon: Weapon) -> &str {
+        &self.weapons[weapon].theirs
+    }
+
+    pub fn beats(&self, a: Weapon, b: Weapon) -> bool {
+        self.beats[a][b]
+    }
+
+    pub fn score(&self, weapon: Weapon) -> i32 {
+        weapon as i32 + 1
+    }
+
+    /// The outcome of a round for the player of `mine`.
+    pub fn outcome(&self, theirs: Weapon, mine: Weapon) -> WinLoseDraw {
+        if theirs == mine {
+            WinLoseDraw::Draw
+        } else if self.beats(mine, theirs) {
+            WinLoseDraw::Win
+        } else {
+            WinLoseDraw::Lose
+        }
+    }
+
+    /// The weapon to play against `theirs` to get `outcome`, the least
+    /// valuable one if there are several.
+    pub fn weapon_for(&self, theirs: Weapon, outcome: WinLoseDraw) -> Weapon {
+        (0..self.len())
+            .find(|&mine| self.outcome(theirs, mine) == outcome)
+            .expect("every weapon should be able to win and lose")
+    }
+
+    /// Reads a weapon from the first column of the guide, or from the
+    /// second one when `mine` is set.
+    pub fn weapon(&self, letter: &str, mine: bool) -> Result<Weapon, ParseError> {
+        let letters = || {
+            self.weapons.iter().map(move |w| match mine {
+                true => w.mine.as_str(),
+                false => w.theirs.as_str(),
+            })
+        };
+        letters().position(|l| l == letter).ok_or_else(|| {
+            let expected: Vec<&str> = letters().collect();
+            ParseError::unexpected(1, format!("one of {}", expected.join(" ")), letter)
+        })
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(rules: &str) -> Result<Self, Self::Err> {
+        let mut weapons: Vec<WeaponInfo> = Vec::new();
+        let mut wins = Vec::new();
+
+        for (i, line) in rules.lines().enumerate() {
+            let words: Vec<(usize, &str)> = tokens(line).collect();
+            let end = line.len() + 1;
+            match words[..] {
+                [] => (),
+                [(_, a), (_, "beats"), (_, b)] => wins.push((i, a, b)),
+                [(n, name), (t, theirs), (m, mine)] => {
+                    let error = |col, expected, found| {
+                        Err(ParseError::unexpected(col, expected, found).offset(i, 0))
+                    };
+                    if weapons.iter().any(|w| w.name == name) {
+                        return error(n, "a weapon not declared yet", name);
+                    }
+                    if weapons.iter().any(|w| w.theirs == theirs) {
+                        return error(t, "a letter not used yet in this column", theirs);
+                    }
+                    if weapons.iter().any(|w| w.mine == mine) {
+                        return error(m, "a letter not used yet in this column", mine);
+                    }
+                    weapons.push(WeaponInfo {
+                        name: name.to_string(),
+                        theirs: theirs.to_string(),
+                        mine: mine.to_string(),
+                    })
+                }
+                [_, _, _, (col, word), ..] => {
+                    return Err(ParseError::unexpected(col, "end of line", word).offset(i, 0))
+                }
+                _ => {
+                    return Err(
+                        ParseError::missing(end, "a weapon and its letters, or a win").offset(i, 0),
+                    )
+                }
+            }
+        }
+
+        let n = weapons.len();
+        let find = |i: usize, name: &str| {
+            weapons
+                .iter()
+                .position(|w| w.name == name)
+                .ok_or_else(|| ParseError::unexpected(1, "a declared weapon", name).offset(i, 0))
+        };
+        let mut beats = vec![vec![false; n]; n];
+        for &(i, a, b) in &wins {
+            let (a, b) = (find(i, a)?, find(i, b)?);
+            if a == b || beats[b][a] {
+                return Err(ParseError::unexpected(
+                    1,
+                    "a consistent win",
+                    rules.lines().nth(i).unwrap(),
+                )
+                .offset(i, 0));
+            }
+            beats[a][b] = true;
+        }
+
+        // fewer weapons can't all both win and lose
+        let end = rules.lines().count();
+        if n < 3 {
+            return Err(ParseError::missing(1, "at least 3 weapons").offset(end, 0));
+        }
+        for a in 0..n {
+            for b in a + 1..n {
+                if !beats[a][b] && !beats[b][a] {
+                    let rule = format!(
+                        "a winner between {} and {}",
+                        weapons[a].name, weapons[b].name
+                    );
+                    return Err(ParseError::missing(1, rule).offset(end, 0));
+                }
+            }
+            if !beats[a].contains(&true) || !(0..n).any(|b| beats[b][a]) {
+                let rule = format!("{} to both win and lose", weapons[a].name);
+                return Err(ParseError::missing(1, rule).offset(end, 0));
+            }
+        }
+
+        Ok(Game { weapons, beats })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rpsls() {
+        let game: Game = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse().unwrap();
+        assert_eq!(game.len(), 5);
+        let spock = game.weapon("E", false).unwrap();
+        let lizard = game.weapon("Y", true).unwrap();
+        assert_eq!(game.name(spock), "Spock");
+        assert_eq!(game.outcome(spock, lizard), WinLoseDraw::Win);
+        assert_eq!(game.weapon_for(spock, WinLoseDraw::Lose), 0);
+        assert_eq!(game.score(lizard), 4);
+        assert_eq!(
+            game.weapon("Q", true),
+            Err(ParseError::unexpected(1, "one of V W X Y Z", "Q"))
+        );
+
+        let cyclic = Game::cyclic(
+            &["Rock", "Spock", "Paper", "Lizard", "Scissors"],
+            &["A", "E", "B", "D", "C"],
+            &["V", "Z", "W", "Y", "X"],
+        );
+        for a in 0..5 {
+            for b in 0..5 {
+                let (ca, cb) = (
+                    cyclic.weapon(game.letter(a), true).unwrap(),
+                    cyclic.weapon(game.letter(b), true).unwrap(),
+                );
+                assert_eq!(game.beats(a, b), cyclic.beats(ca, cb));
+            }
+        }
+    }
+
+    #[test]
+    fn test_classic_is_cyclic() {
+        let cyclic = Game::cyclic(
+            &["Rock", "Paper", "Scissors"],
+            &["A", "B", "C"],
+            &["X", "Y", "Z"],
+        );
+        assert_eq!(Game::classic(), cyclic);
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let e = "Rock A X\nPaper B Y\nPaper beats Stone"
+            .parse::<Game>()
+            .err()
+            .unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(1, "a declared weapon", "Stone").offset(2, 0)
+        );
+
+        let e = "Rock A X\nPaper B Y\nRock beats Paper\nPaper beats Rock"
+            .parse::<Game>()
+            .err()
+            .unwrap();
+        assert_eq!((e.line(), e.expected()), (4, "a consistent win"));
+
+        let e = "Rock A X\nPaper B Y\nScissors C Z\nRock beats Scissors\nPaper beats Rock"
+            .parse::<Game>()
+            .err()
+            .unwrap();
+        assert_eq!(e.expected(), "a winner between Paper and Scissors");
+
+        let e = "Rock A X\nRock B Y".parse::<Game>().err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(1, "a weapon not declared yet", "Rock").offset(1, 0)
+        );
+        let e = "Rock A X\nPaper A Y".parse::<Game>().err().unwrap();
+        assert_eq!((e.line(), e.column()), (2, 7));
+        let e = "Rock A X\nPaper  B X".parse::<Game>().err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(10, "a letter not used yet in this column", "X").offset(1, 0)
+        );
+        assert!("Rock X Y\nPaper Y X\nPaper beats Rock\nRock beats Paper"
+            .parse::<Game>()
+            .err()
+            .is_some_and(|e| e.expected() == "a consistent win"));
+
+        let e = "".parse::<Game>().err().unwrap();
+        assert_eq!(e, ParseError::missing(1, "at least 3 weapons"));
+        let e = "Rock A X\nPaper B Y\nPaper beats Rock\n"
+            .parse::<Game>()
+            .err()
+            .unwrap();
+        assert_eq!(e, ParseError::missing(1, "at least 3 weapons").offset(3, 0));
+
+        let e = "Rock A X\nPaper".parse::<Game>().err().unwrap();
+        assert_eq!(e.line(), 2);
+        let e = "Rock A X Y".parse::<Game>().err().unwrap();
+        assert_eq!(e, ParseError::unexpected(10, "end of line", "Y"));
+    }
+}
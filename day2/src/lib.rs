@@ -0,0 +1,309 @@
+use std::str::FromStr;
+
+use common::{tokens, ParseError, Solution};
+
+pub mod game;
+pub mod report;
+pub mod simulate;
+pub mod solve;
+
+pub use game::{Game, Weapon, WinLoseDraw};
+
+// reads a weapon from the first column of the guide, or from the second one
+// when `mine` is set
+fn convert_to_rps(game: &Game, val: &str, mine: bool) -> Result<Weapon, ParseError> {
+    game.weapon(val, mine)
+}
+
+fn convert_to_wld(val: &str) -> Result<WinLoseDraw, ParseError> {
+    match val {
+        "X" => Ok(WinLoseDraw::Lose),
+        "Y" => Ok(WinLoseDraw::Draw),
+        "Z" => Ok(WinLoseDraw::Win),
+        _ => Err(ParseError::unexpected(1, "one of X Y Z", val)),
+    }
+}
+
+// reads both columns of a line, converting each with the given function
+fn split_columns<T, U>(
+    line: &str,
+    first: impl Fn(&str) -> Result<T, ParseError>,
+    second: impl Fn(&str) -> Result<U, ParseError>,
+) -> Result<(T, U), ParseError> {
+    let mut content = tokens(line);
+    let end = line.chars().count() + 1;
+
+    let (col, theirs) = content
+        .next()
+        .ok_or_else(|| ParseError::missing(end, "their move"))?;
+    let theirs = first(theirs).map_err(|e| e.offset(0, col - 1))?;
+    let (col, mine) = content
+        .next()
+        .ok_or_else(|| ParseError::missing(end, "my move"))?;
+    let mine = second(mine).map_err(|e| e.offset(0, col - 1))?;
+    Ok((theirs, mine))
+}
+
+fn line_to_play(game: &Game, line: &str) -> Result<(Weapon, Weapon), ParseError> {
+    split_columns(
+        line,
+        |theirs| convert_to_rps(game, theirs, false),
+        |mine| convert_to_rps(game, mine, true),
+    )
+}
+
+fn counterplay_to_play(game: &Game, (theirs, outcome): (Weapon, WinLoseDraw)) -> (Weapon, Weapon) {
+    (theirs, game.weapon_for(theirs, outcome))
+}
+
+fn line_to_counterplay(game: &Game, line: &str) -> Result<(Weapon, Weapon), ParseError> {
+    split_columns(
+        line,
+        |theirs| convert_to_rps(game, theirs, false),
+        convert_to_wld,
+    )
+    .map(|counter| counterplay_to_play(game, counter))
+}
+
+fn calculate_outcome(game: &Game, (theirs, mine): (Weapon, Weapon)) -> i32 {
+    game.outcome(theirs, mine) as i32 + game.score(mine)
+}
+
+pub struct Day2;
+
+/// How to read the second column of the strategy guide.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interpretation {
+    /// the move to play, as first guessed in part 1
+    Moves,
+    /// the outcome the round must have, as explained in part 2
+    Outcomes,
+}
+
+impl FromStr for Interpretation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "moves" => Ok(Interpretation::Moves),
+            "outcomes" => Ok(Interpretation::Outcomes),
+            _ => Err(ParseError::unexpected(1, "`moves` or `outcomes`", s)),
+        }
+    }
+}
+
+/// A line of the strategy guide: their move, and my move under each
+/// interpretation the guide was read with.
+pub struct Round {
+    theirs: Weapon,
+    play: Option<Weapon>,
+    counterplay: Option<Weapon>,
+}
+
+impl Round {
+    pub fn theirs(&self) -> Weapon {
+        self.theirs
+    }
+
+    /// Their move and mine. Panics if the guide wasn't read as
+    /// `interpretation`.
+    pub fn moves(&self, interpretation: Interpretation) -> (Weapon, Weapon) {
+        let mine = match interpretation {
+            Interpretation::Moves => self.play,
+            Interpretation::Outcomes => self.counterplay,
+        };
+        let mine = mine.expect("the guide should have been read with this interpretation");
+        (self.theirs, mine)
+    }
+}
+
+/// A strategy guide, with the rules of the game it is for.
+pub struct Guide {
+    pub game: Game,
+    /// the interpretations the second column was read with
+    pub interpretations: Vec<Interpretation>,
+    pub rounds: Vec<Round>,
+}
+
+/// Reads a strategy guide for `game`, one round per line, with each of
+/// `interpretations` of the second column.
+pub fn parse_guide(
+    game: Game,
+    input: &str,
+    interpretations: &[Interpretation],
+) -> Result<Guide, ParseError> {
+    let read_line = |line: &str| {
+        let (theirs, ()) = split_columns(
+            line,
+            |theirs| convert_to_rps(&game, theirs, false),
+            |_| Ok(()),
+        )?;
+        let mut round = Round {
+            theirs,
+            play: None,
+            counterplay: None,
+        };
+        for interpretation in interpretations {
+            match interpretation {
+                Interpretation::Moves => round.play = Some(line_to_play(&game, line)?.1),
+                Interpretation::Outcomes => {
+                    round.counterplay = Some(line_to_counterplay(&game, line)?.1)
+                }
+            }
+        }
+        Ok(round)
+    };
+    let rounds = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| read_line(line).map_err(|e: ParseError| e.offset(i, 0)))
+        .collect::<Result<_, ParseError>>()?;
+    Ok(Guide {
+        game,
+        interpretations: interpretations.to_vec(),
+        rounds,
+    })
+}
+
+impl Guide {
+    /// Score of `round` read as `interpretation`, which the guide must have
+    /// been read with.
+    pub fn round_score(&self, round: &Round, interpretation: Interpretation) -> i32 {
+        calculate_outcome(&self.game, round.moves(interpretation))
+    }
+
+    /// Total score when following the guide read as `interpretation`.
+    pub fn score(&self, interpretation: Interpretation) -> i32 {
+        self.rounds
+            .iter()
+            .map(|r| self.round_score(r, interpretation))
+            .sum()
+    }
+}
+
+impl Solution for Day2 {
+    type Parsed = Guide;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_guide(
+            Game::classic(),
+            input,
+            &[Interpretation::Moves, Interpretation::Outcomes],
+        )
+    }
+
+    fn part1(guide: &Self::Parsed) -> Self::Answer1 {
+        guide.score(Interpretation::Moves)
+    }
+
+    fn part2(guide: &Self::Parsed) -> Self::Answer2 {
+        guide.score(Interpretation::Outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+
+    const ROCK: Weapon = 0;
+    const PAPER: Weapon = 1;
+    const SCISSORS: Weapon = 2;
+
+    #[test]
+    fn test_convert_to_rps() {
+        let game = Game::classic();
+        assert_eq!(convert_to_rps(&game, "A", false).unwrap(), ROCK);
+        assert_eq!(convert_to_rps(&game, "X", true).unwrap(), ROCK);
+        assert_eq!(convert_to_rps(&game, "B", false).unwrap(), PAPER);
+        assert_eq!(convert_to_rps(&game, "Y", true).unwrap(), PAPER);
+        assert_eq!(convert_to_rps(&game, "C", false).unwrap(), SCISSORS);
+        assert_eq!(convert_to_rps(&game, "Z", true).unwrap(), SCISSORS);
+        assert!(convert_to_rps(&game, "k", false).is_err());
+        assert!(convert_to_rps(&game, "X", false).is_err());
+        assert!(convert_to_rps(&game, "A", true).is_err());
+    }
+
+    #[test]
+    fn test_convert_to_wld() {
+        assert_eq!(convert_to_wld("X").unwrap(), WinLoseDraw::Lose);
+        assert_eq!(convert_to_wld("Y").unwrap(), WinLoseDraw::Draw);
+        assert_eq!(convert_to_wld("Z").unwrap(), WinLoseDraw::Win);
+    }
+
+    #[test]
+    fn test_outcome() {
+        let game = Game::classic();
+        // Draws
+        assert_eq!(calculate_outcome(&game, (SCISSORS, SCISSORS)), 6);
+        assert_eq!(calculate_outcome(&game, (ROCK, ROCK)), 4);
+        assert_eq!(calculate_outcome(&game, (PAPER, PAPER)), 5);
+
+        // Wins
+        assert_eq!(calculate_outcome(&game, (PAPER, SCISSORS)), 9);
+        assert_eq!(calculate_outcome(&game, (ROCK, PAPER)), 8);
+        assert_eq!(calculate_outcome(&game, (SCISSORS, ROCK)), 7);
+
+        // Losses
+        assert_eq!(calculate_outcome(&game, (PAPER, ROCK)), 1);
+        assert_eq!(calculate_outcome(&game, (ROCK, SCISSORS)), 3);
+        assert_eq!(calculate_outcome(&game, (SCISSORS, PAPER)), 2);
+    }
+
+    #[test]
+    fn test_line_to_play() {
+        let game = Game::classic();
+        assert_eq!(line_to_play(&game, "A X").unwrap(), (ROCK, ROCK));
+        assert_eq!(line_to_play(&game, "B X").unwrap(), (PAPER, ROCK));
+        assert_eq!(line_to_play(&game, "C X").unwrap(), (SCISSORS, ROCK));
+        assert_eq!(line_to_play(&game, "C Y").unwrap(), (SCISSORS, PAPER));
+        assert_eq!(line_to_play(&game, "C Z").unwrap(), (SCISSORS, SCISSORS));
+        assert!(line_to_play(&game, "x Z").is_err());
+        assert!(line_to_play(&game, "A").is_err());
+        assert!(line_to_play(&game, "A x").is_err());
+        assert!(line_to_play(&game, "X A").is_err());
+
+        // letters are only looked up in their own column
+        let game = "Rock X Y\nPaper Y Z\nScissors Z X\nRock beats Scissors\nPaper beats Rock\nScissors beats Paper"
+            .parse()
+            .unwrap();
+        assert_eq!(line_to_play(&game, "X Y").unwrap(), (ROCK, ROCK));
+        assert_eq!(calculate_outcome(&game, (ROCK, ROCK)), 4);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let e = Day2::parse("A X\nB  k").err().unwrap();
+        assert_eq!((e.line(), e.column()), (2, 4));
+        assert_eq!(e.expected(), "one of X Y Z");
+    }
+
+    #[test]
+    fn test_rpsls_guide() {
+        let game = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse().unwrap();
+        let guide = parse_guide(
+            game,
+            "E Y\nD Z\nA X",
+            &[Interpretation::Moves, Interpretation::Outcomes],
+        )
+        .unwrap();
+        // lizard poisons spock, lizard poisons spock, rock crushes scissors
+        assert_eq!(Day2::part1(&guide), (6 + 4) + 5 + 3);
+        // draw against spock, rock crushes lizard, rock crushes scissors
+        assert_eq!(Day2::part2(&guide), (3 + 5) + (6 + 1) + 3);
+    }
+
+    #[test]
+    fn test_single_interpretation() {
+        let game: Game = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse().unwrap();
+        // V is only a move, so the line can't be read as an outcome
+        let guide = parse_guide(game.clone(), "A V\nE W", &[Interpretation::Moves]).unwrap();
+        assert_eq!(guide.score(Interpretation::Moves), (3 + 1) + (6 + 2));
+        let e = parse_guide(game, "A V", &[Interpretation::Outcomes])
+            .err()
+            .unwrap();
+        assert_eq!((e.column(), e.expected()), (3, "one of X Y Z"));
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use crate::Guide;
+
+/// Every round of a guide scored under each interpretation it was read with,
+/// side by side.
+pub struct Report<'a> {
+    guide: &'a Guide,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(guide: &'a Guide) -> Self {
+        Report { guide }
+    }
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let game = &self.guide.game;
+        let interpretations = &self.guide.interpretations;
+        write!(f, "{:>5}  {:<10}", "round", "theirs")?;
+        for _ in interpretations {
+            write!(f, " | {:<10} {:<6} {:>5}", "move", "result", "score")?;
+        }
+        writeln!(f)?;
+        for (i, round) in self.guide.rounds.iter().enumerate() {
+            let theirs = round.theirs();
+            write!(f, "{:>5}  {:<10}", i + 1, game.name(theirs))?;
+            for &interpretation in interpretations {
+                let (_, mine) = round.moves(interpretation);
+                write!(
+                    f,
+                    " | {:<10} {:<6} {:>5}",
+                    game.name(mine),
+                    format!("{:?}", game.outcome(theirs, mine)),
+                    self.guide.round_score(round, interpretation)
+                )?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, "{:>5}  {:<10}", "total", "")?;
+        for &interpretation in interpretations {
+            write!(f, " | {:<17} {:>5}", "", self.guide.score(interpretation))?;
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::game::ROCK_PAPER_SCISSORS_LIZARD_SPOCK;
+    use crate::{parse_guide, Day2, Interpretation};
+
+    #[test]
+    fn test_report() {
+        let guide = Day2::parse("A Y\nB X\nC Z").unwrap();
+        let report = Report::new(&guide).to_string();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[1],
+            "    1  Rock       | Paper      Win        8 | Rock       Draw       4"
+        );
+        assert!(lines[4].starts_with("total"));
+        assert!(lines[4].ends_with("   15 |                      12"));
+    }
+
+    #[test]
+    fn test_single_mode() {
+        let game = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.parse().unwrap();
+        let guide = parse_guide(game, "A V\nE W", &[Interpretation::Moves]).unwrap();
+        let report = Report::new(&guide).to_string();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "round  theirs     | move       result score");
+        assert_eq!(lines[2], "    2  Spock      | Paper      Win        8");
+        assert!(lines[3].ends_with("|                      12"));
+    }
+}
@@ -0,0 +1,276 @@
+use std::error::Error;
+use std::fmt;
+
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{calculate_outcome, Guide, Interpretation, Weapon, WinLoseDraw};
+
+/// How an opponent who doesn't follow the guide picks its moves.
+#[derive(Debug, Clone)]
+pub struct Opponent {
+    model: Model,
+}
+
+#[derive(Debug, Clone)]
+enum Model {
+    /// independently of each other, with a weight for each weapon
+    Weighted(Vec<f64>),
+    /// depending on its previous move, with a row of weights for each
+    Markov {
+        first: Vec<f64>,
+        transitions: Vec<Vec<f64>>,
+    },
+}
+
+/// Why weights can't model an opponent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WeightError {
+    /// not one weight per weapon
+    Count { expected: usize, found: usize },
+    /// a negative, infinite or NaN weight
+    Invalid(f64),
+    /// no weapon is ever picked
+    AllZero,
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeightError::Count { expected, found } => {
+                write!(
+                    f,
+                    "expected {} weights, one per weapon, found {}",
+                    expected, found
+                )
+            }
+            WeightError::Invalid(w) => {
+                write!(f, "expected finite non-negative weights, found {}", w)
+            }
+            WeightError::AllZero => write!(f, "expected a positive weight"),
+        }
+    }
+}
+
+impl Error for WeightError {}
+
+impl Opponent {
+    /// Opponent picking its moves independently of each other, with a
+    /// weight for each of the `weapons` of the game.
+    pub fn weighted(weights: Vec<f64>, weapons: usize) -> Result<Self, WeightError> {
+        if weights.len() != weapons {
+            return Err(WeightError::Count {
+                expected: weapons,
+                found: weights.len(),
+            });
+        }
+        if let Some(&w) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+            return Err(WeightError::Invalid(w));
+        }
+        if !weights.iter().any(|&w| w > 0.0) {
+            return Err(WeightError::AllZero);
+        }
+        Ok(Opponent {
+            model: Model::Weighted(weights),
+        })
+    }
+
+    pub fn uniform(weapons: usize) -> Result<Self, WeightError> {
+        Opponent::weighted(vec![1.0; weapons], weapons)
+    }
+
+    /// Number of weapons of the game the opponent plays.
+    pub fn weapons(&self) -> usize {
+        match &self.model {
+            Model::Weighted(weights) => weights.len(),
+            Model::Markov { first, .. } => first.len(),
+        }
+    }
+
+    /// Markov model of the first column of `guide`. Every transition is
+    /// counted once more than seen, so that none is impossible.
+    pub fn learn(guide: &Guide) -> Self {
+        let n = guide.game.len();
+        let mut first = vec![1.0; n];
+        let mut transitions = vec![vec![1.0; n]; n];
+        let mut theirs = guide.rounds.iter().map(|r| r.theirs());
+        if let Some(w) = theirs.next() {
+            first[w] += 1.0;
+            theirs.fold(w, |previous, w| {
+                transitions[previous][w] += 1.0;
+                w
+            });
+        }
+        Opponent {
+            model: Model::Markov { first, transitions },
+        }
+    }
+
+    fn distributions(&self) -> Vec<WeightedIndex<f64>> {
+        let rows = match &self.model {
+            Model::Weighted(weights) => vec![weights],
+            Model::Markov { first, transitions } => {
+                std::iter::once(first).chain(transitions).collect()
+            }
+        };
+        rows.into_iter()
+            .map(|w| WeightedIndex::new(w).expect("the weights were checked"))
+            .collect()
+    }
+}
+
+/// Scores of the guide over many simulated tournaments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    pub runs: usize,
+    pub mean: f64,
+    pub variance: f64,
+    pub wins: f64,
+    pub draws: f64,
+    pub losses: f64,
+}
+
+/// Plays the guide read as `interpretation` against `opponent` in `runs`
+/// tournaments of as many rounds as the guide has. The same `seed` gives
+/// the same results.
+///
+/// Panics if `opponent` isn't for a game of as many weapons as the guide's.
+pub fn simulate(
+    guide: &Guide,
+    interpretation: Interpretation,
+    opponent: &Opponent,
+    runs: usize,
+    seed: u64,
+) -> Simulation {
+    assert_eq!(
+        opponent.weapons(),
+        guide.game.len(),
+        "the opponent should play the game of the guide"
+    );
+    let mut rng = StdRng::seed_from_u64(seed);
+    let distributions = opponent.distributions();
+    let markov = matches!(opponent.model, Model::Markov { .. });
+
+    let mut scores = Vec::with_capacity(runs);
+    let mut outcomes = [0usize; 3];
+    for _ in 0..runs {
+        let mut previous: Option<Weapon> = None;
+        let mut score = 0;
+        for round in &guide.rounds {
+            let row = match previous {
+                Some(w) if markov => w + 1,
+                _ => 0,
+            };
+            let theirs = distributions[row].sample(&mut rng);
+            let (_, mine) = round.moves(interpretation);
+            score += calculate_outcome(&guide.game, (theirs, mine));
+            outcomes[match guide.game.outcome(theirs, mine) {
+                WinLoseDraw::Win => 0,
+                WinLoseDraw::Draw => 1,
+                WinLoseDraw::Lose => 2,
+            }] += 1;
+            previous = Some(theirs);
+        }
+        scores.push(score as f64);
+    }
+
+    let n = runs.max(1) as f64;
+    let mean = scores.iter().sum::<f64>() / n;
+    let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+    let rounds = outcomes.iter().sum::<usize>().max(1) as f64;
+    Simulation {
+        runs,
+        mean,
+        variance,
+        wins: outcomes[0] as f64 / rounds,
+        draws: outcomes[1] as f64 / rounds,
+        losses: outcomes[2] as f64 / rounds,
+    }
+}
+
+impl fmt::Display for Simulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "runs:     {}", self.runs)?;
+        writeln!(
+            f,
+            "score:    {:.1} (std dev {:.1})",
+            self.mean,
+            self.variance.sqrt()
+        )?;
+        writeln!(
+            f,
+            "outcomes: {:.1}% won, {:.1}% drawn, {:.1}% lost",
+            self.wins * 100.0,
+            self.draws * 100.0,
+            self.losses * 100.0
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+
+    use super::*;
+    use crate::Day2;
+
+    #[test]
+    fn test_fixed_opponent() {
+        let guide = Day2::parse("A Y\nB X\nC Z").unwrap();
+        // always rock, as in the first round
+        let rock = Opponent::weighted(vec![1.0, 0.0, 0.0], 3).unwrap();
+        let s = simulate(&guide, Interpretation::Moves, &rock, 10, 1);
+        assert_eq!(s.mean, (6.0 + 2.0) + (3.0 + 1.0) + 3.0);
+        assert_eq!(s.variance, 0.0);
+        assert_eq!(
+            (s.wins, s.draws, s.losses),
+            (1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0)
+        );
+    }
+
+    #[test]
+    fn test_seeded_runs() {
+        let guide = Day2::parse("A Y\nB X\nC Z\nA Y\nA X").unwrap();
+        let opponent = Opponent::learn(&guide);
+        let s = simulate(&guide, Interpretation::Outcomes, &opponent, 200, 7);
+        assert_eq!(
+            s,
+            simulate(&guide, Interpretation::Outcomes, &opponent, 200, 7)
+        );
+        assert!((s.wins + s.draws + s.losses - 1.0).abs() < 1e-9);
+        assert!(s.variance > 0.0);
+
+        match opponent.model {
+            Model::Markov { first, transitions } => {
+                assert_eq!(first, vec![2.0, 1.0, 1.0]);
+                // A -> B and A -> A
+                assert_eq!(transitions[0], vec![2.0, 2.0, 1.0]);
+            }
+            _ => panic!("a learned opponent should be a Markov model"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_weights() {
+        assert_eq!(
+            Opponent::weighted(vec![1.0, 2.0], 3).err(),
+            Some(WeightError::Count {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            Opponent::weighted(vec![1.0, -1.0, 1.0], 3).err(),
+            Some(WeightError::Invalid(-1.0))
+        );
+        assert!(Opponent::weighted(vec![1.0, f64::NAN, 1.0], 3).is_err());
+        assert!(Opponent::weighted(vec![f64::INFINITY, 1.0, 1.0], 3).is_err());
+        assert_eq!(
+            Opponent::weighted(vec![0.0; 3], 3).err(),
+            Some(WeightError::AllZero)
+        );
+        assert_eq!(Opponent::uniform(0).err(), Some(WeightError::AllZero));
+        assert_eq!(Opponent::uniform(5).unwrap().weapons(), 5);
+    }
+}
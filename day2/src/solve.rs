@@ -0,0 +1,190 @@
+use common::{tokens, ParseError};
+
+use crate::{calculate_outcome, convert_to_rps, Game, Weapon, WinLoseDraw};
+
+const OUTCOMES: [WinLoseDraw; 3] = [WinLoseDraw::Lose, WinLoseDraw::Draw, WinLoseDraw::Win];
+
+/// What the guide should achieve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Maximise,
+    /// exactly this total score
+    Target(i32),
+}
+
+/// Reads the opponent's moves from the first column of each line, anything
+/// after it is ignored.
+pub fn parse_opponent(game: &Game, input: &str) -> Result<Vec<Weapon>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (col, theirs) = tokens(line)
+                .next()
+                .ok_or_else(|| ParseError::missing(line.len() + 1, "their move").offset(i, 0))?;
+            convert_to_rps(game, theirs, false).map_err(|e| e.offset(i, col - 1))
+        })
+        .collect()
+}
+
+// score of asking for `outcome` against `theirs`, as the guide is read back
+fn outcome_score(game: &Game, theirs: Weapon, outcome: WinLoseDraw) -> i32 {
+    calculate_outcome(game, (theirs, game.weapon_for(theirs, outcome)))
+}
+
+/// The outcomes to ask for against each of `theirs` to reach `goal`,
+/// winning at most `max_wins` rounds. `None` when the goal can't be reached.
+pub fn solve(
+    game: &Game,
+    theirs: &[Weapon],
+    goal: Goal,
+    max_wins: Option<usize>,
+) -> Option<Vec<WinLoseDraw>> {
+    let max_wins = max_wins.unwrap_or(theirs.len()).min(theirs.len());
+    let win = |o: usize| (OUTCOMES[o] == WinLoseDraw::Win) as usize;
+
+    // choices[i][state] is the outcome picked for round i to end in state
+    let mut choices: Vec<Vec<u8>> = Vec::with_capacity(theirs.len());
+    let end = match goal {
+        Goal::Maximise => {
+            // best[w]: best score with exactly w wins
+            let mut best = vec![None; max_wins + 1];
+            best[0] = Some(0);
+            for &t in theirs {
+                let mut next = vec![None; max_wins + 1];
+                let mut picked = vec![0; max_wins + 1];
+                for (w, score) in best.iter().enumerate() {
+                    let Some(score) = score else { continue };
+                    for (o, &outcome) in OUTCOMES.iter().enumerate() {
+                        let w = w + win(o);
+                        let candidate = score + outcome_score(game, t, outcome);
+                        if w <= max_wins && next[w].is_none_or(|s| s < candidate) {
+                            next[w] = Some(candidate);
+                            picked[w] = o as u8;
+                        }
+                    }
+                }
+                best = next;
+                choices.push(picked);
+            }
+            (0..=max_wins).max_by_key(|&w| best[w])?
+        }
+        Goal::Target(target) => {
+            // fewest[s]: fewest wins to score exactly s
+            let target = usize::try_from(target).ok()?;
+            // no need to track the scores above the best possible one
+            let best: usize = theirs
+                .iter()
+                .map(|&t| {
+                    OUTCOMES
+                        .iter()
+                        .map(|&o| outcome_score(game, t, o) as usize)
+                        .max()
+                        .unwrap_or(0)
+                })
+                .sum();
+            if target > best {
+                return None;
+            }
+            let mut fewest = vec![None; target + 1];
+            fewest[0] = Some(0);
+            for &t in theirs {
+                let mut next: Vec<Option<usize>> = vec![None; target + 1];
+                let mut picked = vec![0; target + 1];
+                for (s, wins) in fewest.iter().enumerate() {
+                    let Some(wins) = wins else { continue };
+                    for (o, &outcome) in OUTCOMES.iter().enumerate() {
+                        let s = s + outcome_score(game, t, outcome) as usize;
+                        let wins = wins + win(o);
+                        if s <= target && wins <= max_wins && next[s].is_none_or(|n| wins < n) {
+                            next[s] = Some(wins);
+                            picked[s] = o as u8;
+                        }
+                    }
+                }
+                fewest = next;
+                choices.push(picked);
+            }
+            fewest[target]?;
+            target
+        }
+    };
+
+    // walk back from the final state
+    let mut state = end;
+    let mut outcomes = vec![WinLoseDraw::Draw; theirs.len()];
+    for (i, &t) in theirs.iter().enumerate().rev() {
+        let o = choices[i][state] as usize;
+        outcomes[i] = OUTCOMES[o];
+        state -= match goal {
+            Goal::Maximise => win(o),
+            Goal::Target(_) => outcome_score(game, t, OUTCOMES[o]) as usize,
+        };
+    }
+    Some(outcomes)
+}
+
+/// Writes a strategy guide asking for `outcomes`, in the format read back
+/// with the outcome interpretation.
+pub fn write_guide(game: &Game, theirs: &[Weapon], outcomes: &[WinLoseDraw]) -> String {
+    theirs
+        .iter()
+        .zip(outcomes)
+        .map(|(&t, o)| {
+            let letter = match o {
+                WinLoseDraw::Lose => "X",
+                WinLoseDraw::Draw => "Y",
+                WinLoseDraw::Win => "Z",
+            };
+            format!("{} {}\n", game.their_letter(t), letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_guide, Interpretation};
+
+    // solves then scores the guide as it is read back
+    fn score(goal: Goal, max_wins: Option<usize>) -> Option<i32> {
+        let game = Game::classic();
+        let theirs = parse_opponent(&game, "A\nB\nC Z").unwrap();
+        let outcomes = solve(&game, &theirs, goal, max_wins)?;
+        let guide = write_guide(&game, &theirs, &outcomes);
+        let guide = parse_guide(game, &guide, &[Interpretation::Outcomes]).unwrap();
+        let wins = outcomes.iter().filter(|&&o| o == WinLoseDraw::Win).count();
+        assert!(wins <= max_wins.unwrap_or(wins));
+        Some(guide.score(Interpretation::Outcomes))
+    }
+
+    #[test]
+    fn test_maximise() {
+        assert_eq!(score(Goal::Maximise, None), Some(8 + 9 + 7));
+        assert_eq!(score(Goal::Maximise, Some(1)), Some(8 + 5 + 6));
+        assert_eq!(score(Goal::Maximise, Some(0)), Some(4 + 5 + 6));
+    }
+
+    #[test]
+    fn test_target() {
+        assert_eq!(score(Goal::Target(15), None), Some(15));
+        assert_eq!(score(Goal::Target(6), None), Some(6));
+        assert_eq!(score(Goal::Target(24), Some(2)), None);
+        assert_eq!(score(Goal::Target(100), None), None);
+        assert_eq!(score(Goal::Target(-1), None), None);
+        let theirs = [0; 3];
+        assert_eq!(
+            solve(&Game::classic(), &theirs, Goal::Target(2_000_000_000), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_opponent() {
+        let game = Game::classic();
+        let e = parse_opponent(&game, "A\n  Q").err().unwrap();
+        assert_eq!((e.line(), e.column()), (2, 3));
+        assert!(parse_opponent(&game, "A\n").is_ok());
+        assert!(parse_opponent(&game, "A\n\nB").is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashSet;
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use common::{input_arg, parse_or_exit, read_input, Solution};
+use day3::Day3;
+
+// the HashSet pipeline the item masks replaced, kept to measure against
+fn hashset_part1(rucksacks: &[String]) -> u64 {
+    rucksacks
+        .iter()
+        .map(|r| {
+            let (a, b) = r.split_at(r.len() / 2);
+            let a: HashSet<char> = a.chars().collect();
+            b.chars()
+                .filter(|c| a.contains(c))
+                .collect::<HashSet<char>>()
+        })
+        .map(|dupes| dupes.iter().map(priority).sum::<u64>())
+        .sum()
+}
+
+fn hashset_part2(rucksacks: &[String]) -> u64 {
+    let sets: Vec<HashSet<char>> = rucksacks.iter().map(|r| r.chars().collect()).collect();
+    sets.chunks(3)
+        .map(|group| {
+            let inter: HashSet<char> = group[0].intersection(&group[1]).copied().collect();
+            group[2].intersection(&inter).map(priority).sum::<u64>()
+        })
+        .sum()
+}
+
+fn priority(ch: &char) -> u64 {
+    match ch {
+        'a'..='z' => *ch as u64 - 'a' as u64 + 1,
+        _ => *ch as u64 - 'A' as u64 + 27,
+    }
+}
+
+fn median<T: PartialEq + std::fmt::Debug>(
+    iterations: usize,
+    expected: T,
+    f: impl Fn() -> T,
+) -> Duration {
+    let mut times: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            let answer = black_box(f());
+            let elapsed = start.elapsed();
+            assert_eq!(answer, expected, "both pipelines should agree");
+            elapsed
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn compare(name: &str, iterations: usize, masks: impl Fn() -> u64, hashset: impl Fn() -> u64) {
+    let expected = masks();
+    let fast = median(iterations.max(1), expected, masks);
+    let slow = median(iterations.max(1), expected, hashset);
+    println!(
+        "{}: masks {:?}, hashset {:?}, {:.1}x faster",
+        name,
+        fast,
+        slow,
+        slow.as_secs_f64() / fast.as_secs_f64().max(1e-9)
+    );
+}
+
+// usage: day3-bench [input] [iterations]
+// times both parts with item masks against the former HashSet pipeline
+fn main() {
+    let content =
+        read_input(&input_arg("input.txt")).expect("Should have been able to read the file");
+    let iterations: usize = env::args().nth(2).map_or(1000, |n| {
+        n.parse().expect("the iteration count should be a number")
+    });
+
+    let parsed = parse_or_exit::<Day3>(&content);
+    let rucksacks = &parsed.1;
+    compare(
+        "part 1",
+        iterations,
+        || Day3::part1(black_box(&parsed)),
+        || hashset_part1(black_box(rucksacks)),
+    );
+    compare(
+        "part 2",
+        iterations,
+        || Day3::part2(black_box(&parsed)),
+        || hashset_part2(black_box(rucksacks)),
+    );
+}
@@ -0,0 +1,364 @@
+use std::iter;
+use std::ops::BitAnd;
+
+use common::{ParseError, Solution};
+
+pub mod plan;
+pub mod priorities;
+
+pub use priorities::{Priorities, PRIORITIES};
+
+// `input` cut into `n` compartments, its number of items must be divisible
+// by `n`
+fn split_inputs(input: &str, n: usize) -> Vec<&str> {
+    if input.is_ascii() {
+        let size = input.len() / n;
+        return (0..n).map(|i| &input[i * size..(i + 1) * size]).collect();
+    }
+    let starts: Vec<usize> = input
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(iter::once(input.len()))
+        .collect();
+    let size = (starts.len() - 1) / n;
+    (0..n)
+        .map(|i| &input[starts[i * size]..starts[(i + 1) * size]])
+        .collect()
+}
+
+/// Set of item types, bit `i` standing for the item at `i` in the priority
+/// table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Items(u64);
+
+impl Items {
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < 64 && self.0 & 1 << index != 0
+    }
+
+    /// Indices of the items in the set, in increasing order.
+    pub fn indices(self) -> impl Iterator<Item = usize> {
+        let mut mask = self.0;
+        iter::from_fn(move || {
+            let bit = mask.trailing_zeros();
+            mask &= mask.checked_sub(1)?;
+            Some(bit as usize)
+        })
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+}
+
+// items found in all of `inputs`
+fn get_dupes(priorities: &Priorities, inputs: &[&str]) -> Items {
+    inputs
+        .iter()
+        .map(|input| priorities.items(input))
+        .fold(priorities.all(), Items::bitand)
+}
+
+/// How rucksacks are laid out: the number of equal compartments each one is
+/// split into, the number of rucksacks sharing a badge, and the priorities
+/// of the items they hold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    compartments: usize,
+    group: usize,
+    priorities: Priorities,
+}
+
+impl Default for Layout {
+    /// The layout of the puzzle.
+    fn default() -> Self {
+        Layout::new(2, 3)
+    }
+}
+
+impl Layout {
+    pub fn new(compartments: usize, group: usize) -> Self {
+        assert!(
+            compartments > 0 && group > 0,
+            "a layout needs compartments and groups"
+        );
+        Layout {
+            compartments,
+            group,
+            priorities: Priorities::default(),
+        }
+    }
+
+    pub fn with_priorities(mut self, priorities: Priorities) -> Self {
+        self.priorities = priorities;
+        self
+    }
+
+    pub fn compartments(&self) -> usize {
+        self.compartments
+    }
+
+    pub fn group(&self) -> usize {
+        self.group
+    }
+
+    pub fn priorities(&self) -> &Priorities {
+        &self.priorities
+    }
+
+    /// Every error in the rucksacks, at most one per line, the lines
+    /// missing to complete the last group last.
+    pub fn validate(&self, rucksacks: &[String]) -> Vec<ParseError> {
+        let mut errors: Vec<ParseError> = rucksacks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, rucksack)| {
+                self.priorities
+                    .check(rucksack)
+                    .and_then(|()| self.check_compartments(&rucksacks[i..=i]))
+                    .map_err(|e| e.offset(i, 0))
+                    .err()
+            })
+            .collect();
+        errors.extend(self.check_groups(rucksacks).err());
+        errors
+    }
+
+    /// Checks that every item of the rucksacks is in the priority table.
+    pub fn check_items(&self, rucksacks: &[String]) -> Result<(), ParseError> {
+        for (i, rucksack) in rucksacks.iter().enumerate() {
+            self.priorities
+                .check(rucksack)
+                .map_err(|e| e.offset(i, 0))?;
+        }
+        Ok(())
+    }
+
+    /// Checks that every rucksack splits into whole compartments.
+    pub fn check_compartments(&self, rucksacks: &[String]) -> Result<(), ParseError> {
+        for (i, rucksack) in rucksacks.iter().enumerate() {
+            let len = rucksack.chars().count();
+            if !len.is_multiple_of(self.compartments) {
+                return Err(ParseError::unexpected(
+                    1,
+                    format!("a number of items divisible by {}", self.compartments),
+                    format!("{} items", len),
+                )
+                .offset(i, 0));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the rucksacks form whole groups.
+    pub fn check_groups(&self, rucksacks: &[String]) -> Result<(), ParseError> {
+        match rucksacks.len() % self.group {
+            0 => Ok(()),
+            left => Err(ParseError::missing(
+                1,
+                format!(
+                    "{} more rucksacks to complete a group of {}",
+                    self.group - left,
+                    self.group
+                ),
+            )
+            .offset(rucksacks.len(), 0)),
+        }
+    }
+
+    /// Sum of the priorities of the items found in every compartment of a
+    /// rucksack.
+    pub fn duplicates_sum(&self, rucksacks: &[String]) -> Result<u64, ParseError> {
+        self.check_items(rucksacks)?;
+        self.check_compartments(rucksacks)?;
+        Ok(self.unchecked_duplicates_sum(rucksacks))
+    }
+
+    /// Sum of the priorities of the badges, the items found in every
+    /// rucksack of a group.
+    pub fn badges_sum(&self, rucksacks: &[String]) -> Result<u64, ParseError> {
+        self.check_items(rucksacks)?;
+        self.check_groups(rucksacks)?;
+        Ok(self.unchecked_badges_sum(rucksacks))
+    }
+
+    // `duplicates_sum` of rucksacks known to fit the layout
+    fn unchecked_duplicates_sum(&self, rucksacks: &[String]) -> u64 {
+        rucksacks
+            .iter()
+            .map(|r| get_dupes(&self.priorities, &split_inputs(r, self.compartments)))
+            .map(|dupes| self.priorities.sum(dupes))
+            .sum()
+    }
+
+    // `badges_sum` of rucksacks known to fit the layout
+    fn unchecked_badges_sum(&self, rucksacks: &[String]) -> u64 {
+        rucksacks
+            .chunks(self.group)
+            .map(|group| {
+                let refs: Vec<&str> = group.iter().map(String::as_str).collect();
+                get_dupes(&self.priorities, &refs)
+            })
+            .map(|badges| self.priorities.sum(badges))
+            .sum()
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    /// the rucksacks, checked against the layout of the puzzle
+    type Parsed = (Layout, Vec<String>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let layout = Layout::default();
+        let rucksacks: Vec<String> = input.lines().map(String::from).collect();
+        match layout.validate(&rucksacks).into_iter().next() {
+            Some(e) => Err(e),
+            None => Ok((layout, rucksacks)),
+        }
+    }
+
+    fn part1((layout, rucksacks): &Self::Parsed) -> Self::Answer1 {
+        layout.unchecked_duplicates_sum(rucksacks)
+    }
+
+    fn part2((layout, rucksacks): &Self::Parsed) -> Self::Answer2 {
+        layout.unchecked_badges_sum(rucksacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{ParseError, Solution};
+
+    use crate::{get_dupes, split_inputs, Day3, Layout, Priorities};
+
+    #[test]
+    fn test_split_inputs() {
+        assert_eq!(split_inputs("rust", 2), ["ru", "st"]);
+        assert_eq!(split_inputs("rust", 4), ["r", "u", "s", "t"]);
+        assert_eq!(split_inputs("rust", 1), ["rust"]);
+        assert_eq!(split_inputs("ééaé", 2), ["éé", "aé"]);
+        assert_eq!(split_inputs("", 2), ["", ""]);
+    }
+
+    #[test]
+    fn test_get_dupes() {
+        let p = Priorities::default();
+        let dupes = get_dupes(&p, &split_inputs("dictpixc", 2));
+        assert_eq!(2, dupes.len());
+        assert!(dupes.contains(p.index('c').unwrap()));
+        assert!(dupes.contains(p.index('i').unwrap()));
+    }
+
+    #[test]
+    fn test_get_prio_sum() {
+        let p = Priorities::default();
+        let dupes = get_dupes(&p, &split_inputs("dictpixc", 2));
+        let res = p.sum(dupes);
+        assert_eq!(
+            u64::from(p.priority('c').unwrap() + p.priority('i').unwrap()),
+            res
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        let rucksacks: Vec<String> = ["abcaBC", "xyzbXY", "cdebDE", "fgbFGb"]
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+        let pairs = Layout::new(2, 2);
+        let thirds = Layout::new(3, 4);
+        assert_eq!(pairs.duplicates_sum(&rucksacks), Ok(1 + 2));
+        assert_eq!(thirds.duplicates_sum(&rucksacks), Ok(0));
+        assert_eq!(pairs.badges_sum(&rucksacks), Ok(2 + 2));
+        assert_eq!(thirds.badges_sum(&rucksacks), Ok(2));
+
+        let e = Layout::new(4, 1).duplicates_sum(&rucksacks).err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(1, "a number of items divisible by 4", "6 items")
+        );
+        let e = Layout::new(2, 3).badges_sum(&rucksacks).err().unwrap();
+        assert_eq!(e.line(), 5);
+        assert_eq!(e.expected(), "2 more rucksacks to complete a group of 3");
+
+        let e = Day3::parse("abcd\nabc\nab").err().unwrap();
+        assert_eq!(e.line(), 2);
+        assert!(Day3::parse("ab\nab").is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        let rucksacks: Vec<String> = ["abab", "ab1b", "abc", "aébé"]
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+        let errors = Layout::default().validate(&rucksacks);
+        let lines: Vec<(usize, usize)> = errors.iter().map(|e| (e.line(), e.column())).collect();
+        assert_eq!(lines, [(2, 3), (3, 1), (4, 2), (5, 1)]);
+        assert_eq!(
+            Day3::parse("ab\nab1b\nabc").err(),
+            errors.into_iter().next()
+        );
+
+        // no panic on multibyte items once they are in the table
+        let p: Priorities = "a-b 1\né 3".parse().unwrap();
+        let layout = Layout::new(2, 2).with_priorities(p);
+        let rucksacks = &rucksacks[3..];
+        assert!(layout.validate(rucksacks)[0].expected().contains("group"));
+        assert_eq!(layout.duplicates_sum(rucksacks), Ok(3));
+        assert!(Layout::default().duplicates_sum(rucksacks).is_err());
+
+        // sums of large priorities don't overflow
+        let p: Priorities = "a 4000000000".parse().unwrap();
+        let layout = Layout::new(2, 2).with_priorities(p);
+        let rucksacks = ["aa".to_string(), "aa".to_string()];
+        assert_eq!(layout.duplicates_sum(&rucksacks), Ok(8_000_000_000));
+        assert_eq!(layout.badges_sum(&rucksacks), Ok(4_000_000_000));
+    }
+
+    #[test]
+    fn test_iget_prio() {
+        let p = Priorities::default();
+        assert_eq!(Some(1), p.priority('a'));
+        assert_eq!(Some(2), p.priority('b'));
+        assert_eq!(Some(26), p.priority('z'));
+        assert_eq!(Some(27), p.priority('A'));
+        assert_eq!(Some(52), p.priority('Z'));
+        assert_eq!(None, p.priority('é'));
+        assert_eq!(None, p.priority('1'));
+        for ch in ('a'..='z').chain('A'..='Z') {
+            assert_eq!(p.item(p.index(ch).unwrap()), ch);
+        }
+    }
+
+    #[test]
+    fn test_items() {
+        let p = Priorities::default();
+        let items = p.items("vJrwpWtwJgWrhcsFMMfFFhFp");
+        assert_eq!(items.len(), 14);
+        assert!(items.contains(p.index('F').unwrap()) && !items.contains(0));
+        assert_eq!(p.all().len(), 52);
+        assert_eq!(p.sum(p.all()), (1..=52).sum());
+        assert!((p.items("abc") & p.items("ABC")).is_empty());
+        assert_eq!(p.items("zZa").indices().collect::<Vec<_>>(), [0, 25, 51]);
+        assert_eq!(p.items("").indices().count(), 0);
+    }
+}
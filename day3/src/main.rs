@@ -1,107 +1,72 @@
-use std::{collections::HashSet, fs};
+use std::env;
+use std::fs;
+use std::process;
 
-fn split_inputs(input: &str) -> (&str, &str) {
-    input.split_at(input.len() / 2)
-}
-
-fn get_priority(ch: &char) -> u32 {
-    if !ch.is_ascii_alphabetic() {
-        unreachable!()
-    }
-    if ch.is_ascii_lowercase() {
-        *ch as u32 - 96
-    } else if ch.is_ascii_uppercase() {
-        *ch as u32 - 64 + 26
-    } else {
-        unreachable!()
-    }
-}
-
-fn get_dupes(input: (&str, &str)) -> HashSet<char> {
-    let mut ret = HashSet::new();
-    for i in 0..input.0.len() {
-        for j in 0..input.1.len() {
-            let (a, b) = (input.0.chars().nth(i), input.1.chars().nth(j));
-            match (a, b) {
-                (Some(c1), Some(c2)) => {
-                    if c1 == c2 {
-                        ret.insert(c1);
-                    }
-                }
-                _ => break,
-            }
-        }
-    }
-    ret
-}
-
-fn get_prio_sum(v: HashSet<char>) -> u32 {
-    v.iter().map(get_priority).sum()
-}
+use common::{exit_with, input_arg, read_input};
+use day3::plan::plan;
+use day3::{Layout, Priorities};
 
+// usage: day3 [input] [--compartments N] [--group K] [--priorities FILE]
+//             [--plan OUT]
+// without options, rucksacks have two compartments, groups three elves and
+// items the priorities of the puzzle, see `Priorities` for the table format.
+// planning writes the packing list with no item type left in two
+// compartments to OUT, and prints the moves needed and the badges found
 fn main() {
     let content =
-        fs::read_to_string("input.txt").expect("Should have been able to read the file");
-
-    let res: u32 = content
-        .lines()
-        .map(split_inputs)
-        .map(get_dupes)
-        .map(get_prio_sum)
-        .sum();
-    println!("{}", res);
-
-    let lines: Vec<HashSet<char>> = content
-        .lines()
-        .map(|line| {
-            let ret: HashSet<char> = line.chars().collect();
-            ret
-        })
-        .collect();
-    let sum: u32 = lines
-        .chunks(3)
-        .map(|group| {
-            let inter: HashSet<char> = group[0].intersection(&group[1]).copied().collect();
-            let inter2: HashSet<char> = group[2].intersection(&inter).copied().collect();
-            inter2
-        })
-        .map(get_prio_sum)
-        .sum();
-    println!("{}", sum)
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{get_dupes, get_prio_sum, get_priority, split_inputs};
+        read_input(&input_arg("input.txt")).expect("Should have been able to read the file");
 
-    #[test]
-    fn test_split_inputs() {
-        let (a, b) = split_inputs("rust");
-        assert_eq!(a, "ru");
-        assert_eq!(b, "st");
-    }
-
-    #[test]
-    fn test_get_dupes() {
-        let dupes = get_dupes(split_inputs("dictpixc"));
-        assert_eq!(2, dupes.len());
-        assert!(dupes.contains(&'c'));
-        assert!(dupes.contains(&'i'));
+    let (mut compartments, mut group) = (None, None);
+    let mut priorities = Priorities::default();
+    let mut output = None;
+    let mut args = env::args().skip(2);
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .unwrap_or_else(|| exit_with("missing value", &arg));
+        let count = || match value.parse::<usize>() {
+            Ok(0) => exit_with("expected a positive count for", &arg),
+            Ok(n) => n,
+            Err(e) => exit_with("invalid count", e),
+        };
+        match arg.as_str() {
+            "--compartments" => compartments = Some(count()),
+            "--group" => group = Some(count()),
+            "--priorities" => {
+                let table = read_input(&value).expect("the priority table should be readable");
+                priorities = table
+                    .parse()
+                    .unwrap_or_else(|e| exit_with("invalid priority table", e));
+            }
+            "--plan" => output = Some(value),
+            _ => exit_with("unknown option", &arg),
+        }
     }
 
-    #[test]
-    fn test_get_prio_sum() {
-        let dupes = get_dupes(split_inputs("dictpixc"));
-        let res = get_prio_sum(dupes);
-        assert_eq!(get_priority(&'c') + get_priority(&'i'), res);
+    let layout =
+        Layout::new(compartments.unwrap_or(2), group.unwrap_or(3)).with_priorities(priorities);
+    let rucksacks: Vec<String> = content.lines().map(String::from).collect();
+    let errors = layout.validate(&rucksacks);
+    if !errors.is_empty() {
+        for e in &errors {
+            eprintln!("invalid input: {}", e);
+        }
+        process::exit(1);
     }
 
-    #[test]
-    fn test_iget_prio() {
-        assert_eq!(1, get_priority(&'a'));
-        assert_eq!(2, get_priority(&'b'));
-        assert_eq!(26, get_priority(&'z'));
-        assert_eq!(27, get_priority(&'A'));
-        assert_eq!(52, get_priority(&'Z'));
+    if let Some(output) = output {
+        let plan = plan(&layout, &rucksacks).unwrap_or_else(|e| exit_with("invalid input", e));
+        fs::write(&output, plan.packing_list(&rucksacks))
+            .unwrap_or_else(|e| exit_with("can't write the packing list", e));
+        print!("{}", plan);
+        return;
     }
+    let duplicates = layout
+        .duplicates_sum(&rucksacks)
+        .unwrap_or_else(|e| exit_with("invalid input", e));
+    let badges = layout
+        .badges_sum(&rucksacks)
+        .unwrap_or_else(|e| exit_with("invalid input", e));
+    println!("{}", duplicates);
+    println!("{}", badges)
 }
@@ -0,0 +1,290 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use common::ParseError;
+
+use crate::{get_dupes, split_inputs, Layout, Priorities};
+
+/// `count` items of one type moved from a compartment to another, numbered
+/// from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Move {
+    pub item: char,
+    pub from: usize,
+    pub to: usize,
+    pub count: usize,
+}
+
+/// How to repack a rucksack so that no item type is in two compartments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repacking {
+    pub moves: Vec<Move>,
+    /// the rucksack once repacked, in the format of the packing list
+    pub packed: String,
+}
+
+impl Repacking {
+    /// Number of items moved.
+    pub fn moved(&self) -> usize {
+        self.moves.iter().map(|m| m.count).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Badge {
+    Unique(char),
+    Missing,
+    /// several item types are shared by the whole group
+    Ambiguous(Vec<char>),
+}
+
+/// Repackings of every rucksack and badges of every group of a packing
+/// list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    /// `None` for rucksacks whose item types can't be spread over
+    /// compartments of equal size
+    pub rucksacks: Vec<Option<Repacking>>,
+    pub badges: Vec<Badge>,
+}
+
+impl Plan {
+    /// Number of items moved over all rucksacks.
+    pub fn moved(&self) -> usize {
+        self.rucksacks.iter().flatten().map(Repacking::moved).sum()
+    }
+
+    /// The corrected packing list, leaving the rucksacks that can't be
+    /// repacked as they are.
+    pub fn packing_list(&self, rucksacks: &[String]) -> String {
+        self.rucksacks
+            .iter()
+            .zip(rucksacks)
+            .map(|(plan, original)| match plan {
+                Some(r) => format!("{}\n", r.packed),
+                None => format!("{}\n", original),
+            })
+            .collect()
+    }
+}
+
+/// Plans the fewest moves that leave every item type of each rucksack in a
+/// single compartment, compartments keeping the same size, and finds the
+/// badge of each group.
+pub fn plan(layout: &Layout, rucksacks: &[String]) -> Result<Plan, ParseError> {
+    layout.check_items(rucksacks)?;
+    layout.check_compartments(rucksacks)?;
+    layout.check_groups(rucksacks)?;
+    let priorities = layout.priorities();
+
+    let badges = rucksacks
+        .chunks(layout.group())
+        .map(|group| {
+            let refs: Vec<&str> = group.iter().map(String::as_str).collect();
+            let mut items = get_dupes(priorities, &refs)
+                .indices()
+                .map(|i| priorities.item(i));
+            match (items.next(), items.next()) {
+                (None, _) => Badge::Missing,
+                (Some(badge), None) => Badge::Unique(badge),
+                (Some(a), Some(b)) => Badge::Ambiguous([a, b].into_iter().chain(items).collect()),
+            }
+        })
+        .collect();
+
+    Ok(Plan {
+        rucksacks: rucksacks
+            .iter()
+            .map(|r| repack(priorities, &split_inputs(r, layout.compartments())))
+            .collect(),
+        badges,
+    })
+}
+
+fn repack(priorities: &Priorities, compartments: &[&str]) -> Option<Repacking> {
+    let n = compartments.len();
+    let size = compartments[0].chars().count();
+
+    // how many items of each type, by index, are in each compartment
+    let mut counts: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (c, compartment) in compartments.iter().enumerate() {
+        for ch in compartment.chars().filter_map(|ch| priorities.index(ch)) {
+            counts.entry(ch).or_insert_with(|| vec![0; n])[c] += 1;
+        }
+    }
+
+    // every type goes whole to one compartment: from the items in each
+    // compartment so far, the fewest moves and where each type went
+    let mut states: BTreeMap<Vec<usize>, (usize, Vec<usize>)> = BTreeMap::new();
+    states.insert(vec![0; n], (0, Vec::new()));
+    for count in counts.values() {
+        let total: usize = count.iter().sum();
+        let mut next: BTreeMap<Vec<usize>, (usize, Vec<usize>)> = BTreeMap::new();
+        for (filled, (moved, targets)) in &states {
+            for c in (0..n).filter(|&c| filled[c] + total <= size) {
+                let mut filled = filled.clone();
+                filled[c] += total;
+                let moved = moved + total - count[c];
+                if next.get(&filled).is_none_or(|&(m, _)| moved < m) {
+                    let mut targets = targets.clone();
+                    targets.push(c);
+                    next.insert(filled, (moved, targets));
+                }
+            }
+        }
+        states = next;
+    }
+    let (_, targets) = states.remove(&vec![size; n])?;
+
+    let target: BTreeMap<usize, usize> = counts.keys().copied().zip(targets).collect();
+    let moves = counts
+        .iter()
+        .flat_map(|(index, count)| {
+            let to = target[index];
+            count
+                .iter()
+                .enumerate()
+                .filter(move |&(from, &count)| from != to && count > 0)
+                .map(move |(from, &count)| Move {
+                    item: priorities.item(*index),
+                    from,
+                    to,
+                    count,
+                })
+        })
+        .collect();
+
+    // items staying first, in their order, then the ones moved in
+    let packed = (0..n)
+        .flat_map(|to| {
+            let stays = compartments[to].chars();
+            let arrives = (0..n)
+                .filter(move |&from| from != to)
+                .flat_map(move |from| compartments[from].chars());
+            stays
+                .chain(arrives)
+                .filter(|&ch| priorities.index(ch).and_then(|i| target.get(&i)) == Some(&to))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    Some(Repacking { moves, packed })
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repacked = self
+            .rucksacks
+            .iter()
+            .flatten()
+            .filter(|r| !r.moves.is_empty())
+            .count();
+        writeln!(
+            f,
+            "rucksacks: {}, {} to repack",
+            self.rucksacks.len(),
+            repacked
+        )?;
+        writeln!(f, "moves:     {} items", self.moved())?;
+        for (i, r) in self.rucksacks.iter().enumerate() {
+            if r.is_none() {
+                writeln!(f, "rucksack {}: can't be split evenly", i + 1)?;
+            }
+        }
+        let unique = self
+            .badges
+            .iter()
+            .filter(|b| matches!(b, Badge::Unique(_)))
+            .count();
+        writeln!(
+            f,
+            "groups:    {}, {} with a unique badge",
+            self.badges.len(),
+            unique
+        )?;
+        for (i, badge) in self.badges.iter().enumerate() {
+            match badge {
+                Badge::Unique(_) => (),
+                Badge::Missing => writeln!(f, "group {}: no badge", i + 1)?,
+                Badge::Ambiguous(items) => {
+                    let items: Vec<String> = items.iter().map(char::to_string).collect();
+                    writeln!(f, "group {}: badges {}", i + 1, items.join(" "))?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_dupes;
+
+    fn lines(input: &str) -> Vec<String> {
+        input.lines().map(String::from).collect()
+    }
+
+    fn sorted(s: &str) -> Vec<char> {
+        let mut chars: Vec<char> = s.chars().collect();
+        chars.sort();
+        chars
+    }
+
+    #[test]
+    fn test_repack() {
+        let example = lines(include_str!("../examples/example.txt"));
+        let plan = plan(&Layout::default(), &example).unwrap();
+        let p = Priorities::default();
+        for (r, original) in plan.rucksacks.iter().zip(&example) {
+            let r = r.as_ref().unwrap();
+            assert!(get_dupes(&p, &split_inputs(&r.packed, 2)).is_empty());
+            assert_eq!(sorted(&r.packed), sorted(original));
+        }
+        assert_eq!(plan.badges, [Badge::Unique('r'), Badge::Unique('Z')]);
+
+        let r = repack(&p, &["ab", "ab"]).unwrap();
+        assert!(r.packed == "aabb" || r.packed == "bbaa");
+        assert_eq!(r.moved(), 2);
+        // b has to move, then a or c to make room
+        assert_eq!(repack(&p, &["ab", "cb"]).unwrap().moved(), 2);
+        assert_eq!(repack(&p, &["ab", "cd"]).unwrap().moves, []);
+        // a can only share with d
+        let r = repack(&p, &["aaab", "bccd"]).unwrap();
+        assert_eq!(r.packed, "aaadbccb");
+        assert_eq!(
+            r.moves,
+            [
+                Move {
+                    item: 'b',
+                    from: 0,
+                    to: 1,
+                    count: 1
+                },
+                Move {
+                    item: 'd',
+                    from: 1,
+                    to: 0,
+                    count: 1
+                }
+            ]
+        );
+        assert_eq!(repack(&p, &["aab", "bcc", "dde"]), None);
+        assert_eq!(repack(&p, &["aa", "ab"]), None);
+    }
+
+    #[test]
+    fn test_badges() {
+        let rucksacks = lines("abc\nbcd\nbce\nxy\nxz\nyz");
+        let plan = plan(&Layout::new(1, 3), &rucksacks).unwrap();
+        assert_eq!(
+            plan.badges,
+            [Badge::Ambiguous(vec!['b', 'c']), Badge::Missing]
+        );
+        assert_eq!(plan.moved(), 0);
+        assert_eq!(plan.packing_list(&rucksacks), "abc\nbcd\nbce\nxy\nxz\nyz\n");
+        let summary = plan.to_string();
+        assert!(summary.contains("group 1: badges b c"));
+        assert!(summary.contains("group 2: no badge"));
+    }
+}
@@ -0,0 +1,212 @@
+use std::str::FromStr;
+
+use common::{number, tokens, ParseError};
+
+use crate::Items;
+
+/// The priorities of the puzzle, lowercase items first.
+pub const PRIORITIES: &str = "\
+a-z 1
+A-Z 27";
+
+/// Priority of every item type, read from a table of at most 64 of them.
+///
+/// The table lists one item or range of items per line with its priority
+/// (`x 12`), items of a range getting consecutive priorities (`a-z 1`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Priorities {
+    items: Vec<(char, u32)>,
+    // position in `items` of the ASCII ones, the others are searched for
+    ascii: [Option<u8>; 128],
+}
+
+impl Default for Priorities {
+    fn default() -> Self {
+        PRIORITIES
+            .parse()
+            .expect("the puzzle priorities should be valid")
+    }
+}
+
+impl Priorities {
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Position of `item` in the table, which is its bit in [`Items`].
+    pub fn index(&self, item: char) -> Option<usize> {
+        match item.is_ascii() {
+            true => self.ascii[item as usize].map(usize::from),
+            false => self.items.iter().position(|&(ch, _)| ch == item),
+        }
+    }
+
+    pub fn priority(&self, item: char) -> Option<u32> {
+        self.index(item).map(|i| self.items[i].1)
+    }
+
+    /// The item at `index` in the table.
+    pub fn item(&self, index: usize) -> char {
+        self.items[index].0
+    }
+
+    /// Every item type of the table.
+    pub fn all(&self) -> Items {
+        Items(u64::MAX >> (64 - self.len()))
+    }
+
+    /// Set of the item types found in `line`, leaving out the ones that
+    /// aren't in the table.
+    pub fn items(&self, line: &str) -> Items {
+        let mask = match line.is_ascii() {
+            true => line
+                .bytes()
+                .filter_map(|b| self.ascii[b as usize])
+                .fold(0, |mask, i| mask | 1 << i),
+            false => line
+                .chars()
+                .filter_map(|ch| self.index(ch))
+                .fold(0, |mask, i| mask | 1 << i),
+        };
+        Items(mask)
+    }
+
+    /// Checks that every item of `line` is in the table.
+    pub fn check(&self, line: &str) -> Result<(), ParseError> {
+        match line
+            .chars()
+            .enumerate()
+            .find(|&(_, ch)| self.index(ch).is_none())
+        {
+            Some((col, ch)) => Err(ParseError::unexpected(
+                col + 1,
+                "an item of the priority table",
+                ch,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Sum of the priorities of `items`, wide enough for the sums over a
+    /// whole file.
+    pub fn sum(&self, items: Items) -> u64 {
+        items.indices().map(|i| u64::from(self.items[i].1)).sum()
+    }
+}
+
+impl FromStr for Priorities {
+    type Err = ParseError;
+
+    fn from_str(table: &str) -> Result<Self, Self::Err> {
+        let mut priorities = Priorities {
+            items: Vec::new(),
+            ascii: [None; 128],
+        };
+
+        for (i, line) in table.lines().enumerate() {
+            let words: Vec<(usize, &str)> = tokens(line).collect();
+            let (col, items, priority) = match words[..] {
+                [] => continue,
+                [(col, items), (p, priority)] => {
+                    let priority: u32 = number(p, priority).map_err(|e| e.offset(i, 0))?;
+                    (col, items, priority)
+                }
+                [_, _, (col, word), ..] => {
+                    return Err(ParseError::unexpected(col, "end of line", word).offset(i, 0))
+                }
+                _ => {
+                    return Err(
+                        ParseError::missing(line.len() + 1, "an item and its priority")
+                            .offset(i, 0),
+                    )
+                }
+            };
+            let error = |expected: &str| ParseError::unexpected(col, expected, items).offset(i, 0);
+
+            let chars: Vec<char> = items.chars().collect();
+            let range = match chars[..] {
+                [ch] => ch..=ch,
+                [first, '-', last] if first <= last => first..=last,
+                [_, '-', _] => return Err(error("a range in increasing order")),
+                _ => return Err(error("an item or a range of items")),
+            };
+            let last = range.clone().count() as u32 - 1;
+            if priority.checked_add(last).is_none() {
+                return Err(error("a range whose priorities fit in 32 bits"));
+            }
+            for (k, ch) in range.enumerate() {
+                if priorities.index(ch).is_some() {
+                    return Err(error("items not listed yet"));
+                }
+                if priorities.len() == 64 {
+                    return Err(error("at most 64 item types"));
+                }
+                if ch.is_ascii() {
+                    priorities.ascii[ch as usize] = Some(priorities.len() as u8);
+                }
+                priorities.items.push((ch, priority + k as u32));
+            }
+        }
+
+        if priorities.is_empty() {
+            let end = table.lines().count();
+            return Err(ParseError::missing(1, "an item and its priority").offset(end, 0));
+        }
+        Ok(priorities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_table() {
+        let p: Priorities = "é 100\n\n  x-z 5\nA 1".parse().unwrap();
+        assert_eq!(p.len(), 5);
+        assert_eq!(p.priority('é'), Some(100));
+        assert_eq!(p.priority('y'), Some(6));
+        assert_eq!(p.priority('a'), None);
+        assert_eq!(p.sum(p.items("zéq")), 107);
+        assert_eq!(p.all().len(), 5);
+        assert_eq!(
+            p.check("xAyéb"),
+            Err(ParseError::unexpected(
+                5,
+                "an item of the priority table",
+                "b"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_table() {
+        let e = "a 1\nc-a 2".parse::<Priorities>().err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(1, "a range in increasing order", "c-a").offset(1, 0)
+        );
+        let e = "a-c 1\nb 7".parse::<Priorities>().err().unwrap();
+        assert_eq!((e.line(), e.expected()), (2, "items not listed yet"));
+        let e = "a-z 1\nA-Z 27\n0-9 53\n+ 63\n- 64\n* 65".parse::<Priorities>();
+        assert_eq!(e.err().unwrap().expected(), "at most 64 item types");
+        let e = "ab 1".parse::<Priorities>().err().unwrap();
+        assert_eq!(e.expected(), "an item or a range of items");
+        let e = "a one".parse::<Priorities>().err().unwrap();
+        assert_eq!(e.column(), 3);
+        assert_eq!("a".parse::<Priorities>().err().unwrap().column(), 2);
+        assert_eq!("".parse::<Priorities>().err().unwrap().line(), 1);
+        let e = "x 1\n  a-b 4294967295".parse::<Priorities>().err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(3, "a range whose priorities fit in 32 bits", "a-b")
+                .offset(1, 0)
+        );
+        let p: Priorities = "a-b 4294967294".parse().unwrap();
+        assert_eq!(p.sum(p.all()), 2 * u64::from(u32::MAX) - 1);
+    }
+}
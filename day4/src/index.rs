@@ -0,0 +1,157 @@
+use common::{number, ParseError};
+
+use crate::{Range, Section};
+
+/// Index of the assignments of a file, answering which lines hold a range
+/// meeting a section or range of sections.
+///
+/// The ranges are sorted by their start and seen as a balanced tree, the
+/// middle one of a slice being the root of the ones on each side, where each
+/// node knows the furthest end of its subtree. A query only visits the
+/// subtrees that can hold a match.
+#[derive(Debug, Clone)]
+pub struct IntervalIndex<T> {
+    entries: Vec<(Range<T>, usize)>,
+    // furthest end in the subtree rooted at each entry
+    max_high: Vec<T>,
+}
+
+impl<T: Section> IntervalIndex<T> {
+    /// Indexes ranges along with the line they were read from.
+    pub fn new(ranges: impl IntoIterator<Item = (Range<T>, usize)>) -> Self {
+        let mut entries: Vec<(Range<T>, usize)> = ranges.into_iter().collect();
+        entries.sort_by_key(|(r, _)| r.low());
+        let mut max_high: Vec<T> = entries.iter().map(|(r, _)| r.high()).collect();
+        build(&mut max_high, 0, entries.len());
+        IntervalIndex { entries, max_high }
+    }
+
+    /// Indexes both assignments of each pair, numbering lines from 1.
+    pub fn of_pairs(pairs: &[(Range<T>, Range<T>)]) -> Self {
+        IntervalIndex::new(
+            pairs
+                .iter()
+                .enumerate()
+                .flat_map(|(i, &(a, b))| [(a, i + 1), (b, i + 1)]),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Lines with a range holding `section`, in increasing order.
+    pub fn point(&self, section: T) -> Vec<usize> {
+        self.overlapping(&Range::new(section, section))
+    }
+
+    /// Lines with a range overlapping `query`, in increasing order.
+    pub fn overlapping(&self, query: &Range<T>) -> Vec<usize> {
+        let mut lines = Vec::new();
+        self.search(0, self.entries.len(), query, &mut lines);
+        lines.sort_unstable();
+        lines.dedup();
+        lines
+    }
+
+    fn search(&self, start: usize, end: usize, query: &Range<T>, lines: &mut Vec<usize>) {
+        if start >= end {
+            return;
+        }
+        let mid = start + (end - start) / 2;
+        if self.max_high[mid] < query.low() {
+            return;
+        }
+        self.search(start, mid, query, lines);
+        let (range, line) = &self.entries[mid];
+        // the ones on the right start even later
+        if range.low() > query.high() {
+            return;
+        }
+        if range.overlaps(query) {
+            lines.push(*line);
+        }
+        self.search(mid + 1, end, query, lines);
+    }
+}
+
+// stores in `max_high` the furthest end of each subtree of `start..end`,
+// returning the one of its root
+fn build<T: Section>(max_high: &mut [T], start: usize, end: usize) -> Option<T> {
+    if start >= end {
+        return None;
+    }
+    let mid = start + (end - start) / 2;
+    let left = build(max_high, start, mid);
+    let right = build(max_high, mid + 1, end);
+    let high = [left, right]
+        .into_iter()
+        .flatten()
+        .fold(max_high[mid], T::max);
+    max_high[mid] = high;
+    Some(high)
+}
+
+/// Reads a query, either a single section (`57`) or a range (`10-20`).
+pub fn parse_query<T: Section>(query: &str) -> Result<Range<T>, ParseError> {
+    // a leading `-` is the sign of a single section
+    if query.chars().skip(1).any(|c| c == '-') {
+        Range::try_from(query.to_string())
+    } else {
+        let section = number(1, query)?;
+        Ok(Range::new(section, section))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Solution;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::Day4;
+
+    #[test]
+    fn test_queries() {
+        let pairs = Day4::parse(include_str!("../examples/example.txt")).unwrap();
+        let index = IntervalIndex::of_pairs(&pairs);
+        assert_eq!(index.len(), 12);
+        assert_eq!(index.point(7), [1, 3, 4, 6]);
+        assert_eq!(index.point(1), []);
+        assert_eq!(index.overlapping(&Range::new(9, 10)), [3]);
+        assert_eq!(index.overlapping(&Range::new(0, 100)), [1, 2, 3, 4, 5, 6]);
+        assert!(IntervalIndex::<u64>::of_pairs(&[]).point(3).is_empty());
+
+        assert_eq!(parse_query::<u64>("57"), Ok(Range::new(57, 57)));
+        assert_eq!(parse_query::<u64>("10-20"), Ok(Range::new(10, 20)));
+        assert!(parse_query::<u64>("20-10").is_err());
+        assert_eq!(parse_query::<i64>("-5"), Ok(Range::new(-5, -5)));
+        assert_eq!(parse_query::<i64>("-5--3"), Ok(Range::new(-5, -3)));
+        assert!(parse_query::<i64>("-3--5").is_err());
+        assert_eq!(
+            parse_query::<u64>("x").err().unwrap().expected(),
+            "a number"
+        );
+    }
+
+    fn range() -> impl Strategy<Value = Range<i16>> {
+        (any::<i16>(), 0i16..2000).prop_map(|(low, len)| Range::new(low, low.saturating_add(len)))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_index_matches_scan(ranges in prop::collection::vec(range(), 0..60), query in range()) {
+            let index = IntervalIndex::new(ranges.iter().enumerate().map(|(i, &r)| (r, i)));
+            let expected: Vec<usize> = (0..ranges.len()).filter(|&i| ranges[i].overlaps(&query)).collect();
+            prop_assert_eq!(index.overlapping(&query), expected);
+            let point: Vec<usize> = (0..ranges.len())
+                .filter(|&i| ranges[i].contains(&Range::new(query.low(), query.low())))
+                .collect();
+            prop_assert_eq!(index.point(query.low()), point);
+        }
+    }
+}
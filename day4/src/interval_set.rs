@@ -0,0 +1,252 @@
+use std::fmt;
+
+use crate::{Range, Section};
+
+/// Set of sections, kept as sorted ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+}
+
+impl<T: Section> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// The ranges of the set, in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Adds the sections of `range`, merging it with the ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        // ranges before `start` end before the new one, with a gap
+        let start = self
+            .ranges
+            .partition_point(|r| r.high.next().is_some_and(|n| n < range.low));
+        let end = self.ranges[start..]
+            .iter()
+            .position(|r| range.high.next().is_some_and(|n| r.low > n))
+            .map_or(self.ranges.len(), |i| start + i);
+        let merged = self.ranges[start..end].iter().fold(range, |m, r| {
+            Range::new(m.low.min(r.low), m.high.max(r.high))
+        });
+        self.ranges.splice(start..end, [merged]);
+    }
+
+    pub fn contains(&self, section: T) -> bool {
+        let i = self.ranges.partition_point(|r| r.high < section);
+        self.ranges.get(i).is_some_and(|r| r.low <= section)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        self.ranges.iter().chain(&other.ranges).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (low, high) = (a.low.max(b.low), a.high.min(b.high));
+            if low <= high {
+                ranges.push(Range::new(low, high));
+            }
+            // the range ending first can't meet the next ones of the other
+            if a.high < b.high {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Sections of the set that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for a in &self.ranges {
+            // skip the ranges of `other` entirely before this one
+            while other.ranges.get(j).is_some_and(|b| b.high < a.low) {
+                j += 1;
+            }
+            // start of what is left of `a`, if anything
+            let mut low = Some(a.low);
+            for b in other.ranges[j..].iter().take_while(|b| b.low <= a.high) {
+                let Some(l) = low else { break };
+                if let Some(end) = b.low.previous().filter(|&end| end >= l) {
+                    ranges.push(Range::new(l, end));
+                }
+                low = b.high.next().filter(|&next| next <= a.high);
+            }
+            if let Some(l) = low {
+                ranges.push(Range::new(l, a.high));
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Number of sections in the set.
+    pub fn covered(&self) -> u128 {
+        self.ranges.iter().map(Range::sections).sum()
+    }
+
+    /// Ranges of sections missing between the first and last sections of the
+    /// set.
+    pub fn gaps(&self) -> Vec<Range<T>> {
+        self.ranges
+            .windows(2)
+            .filter_map(|w| Some(Range::new(w[0].high.next()?, w[1].low.previous()?)))
+            .collect()
+    }
+
+    /// Smallest range holding every section of the set.
+    pub fn span(&self) -> Option<Range<T>> {
+        Some(Range::new(
+            self.ranges.first()?.low,
+            self.ranges.last()?.high,
+        ))
+    }
+}
+
+impl<T: Section> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut sorted: Vec<Range<T>> = iter.into_iter().collect();
+        sorted.sort_by_key(|r| r.low);
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(sorted.len());
+        for r in sorted {
+            match ranges.last_mut() {
+                Some(last) if last.high.next().is_none_or(|n| r.low <= n) => {
+                    last.high = last.high.max(r.high)
+                }
+                _ => ranges.push(r),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl<T: Section> fmt::Display for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self.ranges.iter().map(Range::to_string).collect();
+        write!(f, "{}", ranges.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn set(ranges: &[(i32, i32)]) -> IntervalSet<i32> {
+        ranges.iter().map(|&(l, h)| Range::new(l, h)).collect()
+    }
+
+    // the same set, a section at a time
+    fn brute(f: impl Fn(i32) -> bool) -> IntervalSet<i32> {
+        (-5..40)
+            .filter(|&x| f(x))
+            .map(|x| Range::new(x, x))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge() {
+        let s = set(&[(10, 12), (1, 3), (4, 5), (11, 20), (30, 30), (7, 7)]);
+        assert_eq!(s.to_string(), "1-5,7-7,10-20,30-30");
+        assert_eq!(s.covered(), 5 + 1 + 11 + 1);
+        assert_eq!(
+            s.gaps(),
+            [Range::new(6, 6), Range::new(8, 9), Range::new(21, 29)]
+        );
+        assert_eq!(s.span(), Some(Range::new(1, 30)));
+        assert!(s.contains(15) && !s.contains(25) && !s.contains(0));
+
+        let mut inserted = IntervalSet::new();
+        for r in [(10, 12), (1, 3), (4, 5), (11, 20), (30, 30), (7, 7)] {
+            inserted.insert(Range::new(r.0, r.1));
+        }
+        assert_eq!(inserted, s);
+        inserted.insert(Range::new(6, 29));
+        assert_eq!(inserted.to_string(), "1-30");
+        inserted.insert(Range::new(i32::MIN, i32::MIN));
+        inserted.insert(Range::new(i32::MAX, i32::MAX));
+        assert_eq!(inserted.ranges().len(), 3);
+        assert_eq!(IntervalSet::<i32>::new().span(), None);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 4), (8, 12), (20, 30), (35, 35)]);
+        let b = set(&[(-2, 1), (3, 9), (12, 22), (25, 26), (28, 35)]);
+        for (x, y) in [(&a, &b), (&b, &a), (&a, &a)] {
+            assert_eq!(x.union(y), brute(|s| x.contains(s) || y.contains(s)));
+            assert_eq!(x.intersection(y), brute(|s| x.contains(s) && y.contains(s)));
+            assert_eq!(x.difference(y), brute(|s| x.contains(s) && !y.contains(s)));
+        }
+        assert_eq!(a.difference(&b).to_string(), "2-2,10-11,23-24,27-27");
+        assert!(a.difference(&a).is_empty());
+        let all = set(&[(i32::MIN, i32::MAX)]);
+        assert_eq!(all.difference(&a).ranges().len(), 5);
+        assert_eq!(all.difference(&all), IntervalSet::new());
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+    }
+
+    fn set_of_sections() -> impl Strategy<Value = (IntervalSet<i8>, BTreeSet<i8>)> {
+        prop::collection::vec((any::<i8>(), any::<i8>()), 0..8).prop_map(|pairs| {
+            let ranges: Vec<Range<i8>> = pairs
+                .iter()
+                .map(|&(a, b)| Range::new(a.min(b), a.max(b)))
+                .collect();
+            let sections = ranges.iter().flat_map(|r| r.low..=r.high).collect();
+            (ranges.into_iter().collect(), sections)
+        })
+    }
+
+    // the set holding exactly `sections`
+    fn from_sections(sections: &BTreeSet<i8>) -> IntervalSet<i8> {
+        sections.iter().map(|&s| Range::new(s, s)).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_matches_sections((a, sa) in set_of_sections(), (b, sb) in set_of_sections()) {
+            prop_assert_eq!(&a, &from_sections(&sa));
+            prop_assert_eq!(a.covered(), sa.len() as u128);
+            prop_assert_eq!(a.union(&b), from_sections(&sa.union(&sb).copied().collect()));
+            prop_assert_eq!(
+                a.intersection(&b),
+                from_sections(&sa.intersection(&sb).copied().collect())
+            );
+            prop_assert_eq!(
+                a.difference(&b),
+                from_sections(&sa.difference(&sb).copied().collect())
+            );
+            let gaps: BTreeSet<i8> = match (sa.first(), sa.last()) {
+                (Some(&first), Some(&last)) => (first..=last).filter(|s| !sa.contains(s)).collect(),
+                _ => BTreeSet::new(),
+            };
+            prop_assert_eq!(a.gaps(), from_sections(&gaps).ranges);
+
+            let mut inserted = IntervalSet::new();
+            for r in b.ranges().iter().chain(a.ranges()) {
+                inserted.insert(*r);
+            }
+            prop_assert_eq!(inserted, a.union(&b));
+        }
+    }
+}
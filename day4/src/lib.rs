@@ -0,0 +1,279 @@
+use std::fmt;
+use std::str::FromStr;
+
+use common::{number, ParseError, Solution};
+
+pub mod index;
+pub mod interval_set;
+
+pub use index::IntervalIndex;
+pub use interval_set::IntervalSet;
+
+/// Integer type usable as a section ID.
+pub trait Section: Copy + Ord + FromStr + fmt::Display + fmt::Debug {
+    /// The section after this one, if there is one.
+    fn next(self) -> Option<Self>;
+
+    /// The section before this one, if there is one.
+    fn previous(self) -> Option<Self>;
+
+    /// Number of sections from `low` to `high`, both included.
+    fn count(low: Self, high: Self) -> u128;
+}
+
+macro_rules! section {
+    ($($t:ty),*) => {$(
+        impl Section for $t {
+            fn next(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn previous(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+
+            fn count(low: Self, high: Self) -> u128 {
+                (high as i128 - low as i128 + 1).max(0) as u128
+            }
+        }
+    )*};
+}
+
+section!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Sections from `low` to `high`, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range<T> {
+    low: T,
+    high: T,
+}
+
+impl<T: Section> Range<T> {
+    pub fn new(low: T, high: T) -> Self {
+        assert!(low <= high, "a range can't end before it starts");
+        Range { low, high }
+    }
+
+    pub fn low(&self) -> T {
+        self.low
+    }
+
+    pub fn high(&self) -> T {
+        self.high
+    }
+
+    /// Number of sections in the range.
+    pub fn sections(&self) -> u128 {
+        T::count(self.low, self.high)
+    }
+
+    pub fn contains(&self, r: &Range<T>) -> bool {
+        self.low <= r.low && self.high >= r.high
+    }
+
+    pub fn overlaps(&self, r: &Range<T>) -> bool {
+        self.low <= r.high && r.low <= self.high
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Range<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.low, self.high)
+    }
+}
+
+impl<T: Section> TryFrom<String> for Range<T> {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        // the first `-` may be the sign of the low bound
+        let (dash, _) = s
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .ok_or_else(|| ParseError::missing(s.len() + 1, "`-`"))?;
+        let (l, h) = (&s[..dash], &s[dash + 1..]);
+
+        let low: T = number(1, l)?;
+        let high: T = number(l.len() + 2, h)?;
+        if high < low {
+            let expected = format!("a section from {} on", low);
+            return Err(ParseError::unexpected(l.len() + 2, expected, h));
+        }
+        Ok(Range { low, high })
+    }
+}
+
+fn parse_line(line: &str) -> Result<(Range<u64>, Range<u64>), ParseError> {
+    let (f, s) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::missing(line.len() + 1, "`,`"))?;
+
+    Ok((
+        Range::try_from(f.to_string())?,
+        Range::try_from(s.to_string()).map_err(|e| e.offset(0, f.len() + 1))?,
+    ))
+}
+
+/// Every section assigned to at least one elf.
+pub fn coverage<T: Section>(pairs: &[(Range<T>, Range<T>)]) -> IntervalSet<T> {
+    pairs.iter().flat_map(|&(a, b)| [a, b]).collect()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<(Range<u64>, Range<u64>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_line(line).map_err(|e| e.offset(i, 0)))
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Parsed) -> Self::Answer1 {
+        pairs
+            .iter()
+            .filter(|p| p.0.contains(&p.1) || p.1.contains(&p.0))
+            .count()
+    }
+
+    fn part2(pairs: &Self::Parsed) -> Self::Answer2 {
+        pairs.iter().filter(|p| p.0.overlaps(&p.1)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
+    use crate::*;
+
+    #[test]
+    fn test_try_from() {
+        let r = Range::<i32>::try_from(String::from("1-100"));
+        assert!(r.is_ok());
+        assert_eq!(r.unwrap(), Range { low: 1, high: 100 });
+
+        let r = Range::<i32>::try_from(String::from("1-"));
+        assert!(r.is_err());
+
+        let r = Range::<i32>::try_from(String::from("1-99.9"));
+        assert!(r.is_err());
+
+        let r = Range::<i32>::try_from(String::from("1-asdasd"));
+        assert!(r.is_err());
+
+        let r = Range::<i32>::try_from(String::from("-5--3"));
+        assert_eq!(r, Ok(Range { low: -5, high: -3 }));
+        let e = Range::<i32>::try_from(String::from("-3--5")).err().unwrap();
+        assert_eq!(e, ParseError::unexpected(4, "a section from -3 on", "-5"));
+        assert!(Range::<i32>::try_from(String::from("-5")).is_err());
+    }
+
+    #[test]
+    fn tets_parse_line() {
+        let r = parse_line("1-2,3-4");
+        assert!(r.is_ok());
+        assert_eq!(
+            r.unwrap(),
+            (Range { low: 1, high: 2 }, Range { low: 3, high: 4 })
+        );
+        let r = parse_line("1-2");
+        assert!(r.is_err());
+        let r = parse_line("1-2,");
+        assert!(r.is_err());
+        let r = parse_line("1-2,3-99.99");
+        assert!(r.is_err());
+        let r = parse_line("1-asjdbna,3-99");
+        assert!(r.is_err());
+        let r = parse_line("1-2,3-x");
+        assert_eq!(r, Err(ParseError::unexpected(7, "a number", "x")));
+    }
+
+    #[test]
+    fn test_coverage() {
+        let pairs = Day4::parse("2-4,6-8\n2-3,4-5\n12-14,13-13").unwrap();
+        let covered = coverage(&pairs);
+        assert_eq!(covered.to_string(), "2-8,12-14");
+        assert_eq!(covered.covered(), 10);
+        assert_eq!(covered.gaps(), [Range::new(9, 11)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        assert!(Range { low: 50, high: 100 }.contains(&Range { low: 55, high: 95 }));
+        assert!(Range { low: 50, high: 100 }.contains(&Range { low: 99, high: 99 }));
+        assert!(Range { low: 4, high: 6 }.contains(&Range { low: 6, high: 6 }));
+
+        assert!(!Range { low: 50, high: 100 }.contains(&Range { low: 55, high: 105 }));
+        assert!(!Range { low: 50, high: 100 }.contains(&Range { low: 45, high: 105 }));
+        assert!(!Range { low: 50, high: 100 }.contains(&Range { low: 45, high: 95 }));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        assert!(Range { low: 5, high: 7 }.overlaps(&Range { low: 7, high: 9 }));
+        assert!(Range { low: 2, high: 8 }.overlaps(&Range { low: 3, high: 7 }));
+        assert!(Range { low: 6, high: 6 }.overlaps(&Range { low: 4, high: 6 }));
+        assert!(Range { low: 2, high: 6 }.overlaps(&Range { low: 4, high: 8 }));
+        assert!(!Range { low: 2, high: 3 }.overlaps(&Range { low: 4, high: 8 }));
+        assert!(!Range { low: 4, high: 8 }.overlaps(&Range { low: 2, high: 3 }));
+
+        // far enough apart to overflow a product of differences
+        let (low, high) = (Range::new(i32::MIN, 0), Range::new(1, i32::MAX));
+        assert!(!low.overlaps(&high) && !high.overlaps(&low));
+        assert!(Range::new(0, u64::MAX).overlaps(&Range::new(u64::MAX, u64::MAX)));
+    }
+
+    #[test]
+    fn test_inverted() {
+        let e = Range::<u64>::try_from(String::from("10-9")).err().unwrap();
+        assert_eq!(e, ParseError::unexpected(4, "a section from 10 on", "9"));
+        let e = parse_line("1-2,30-4").err().unwrap();
+        assert_eq!(e.column(), 8);
+        assert!(Day4::parse("1-2,3-4\n2-1,3-4").is_err());
+        assert!(Range::<u8>::try_from(String::from("0-255")).is_ok());
+        assert!(Range::<u8>::try_from(String::from("0-256")).is_err());
+    }
+
+    // every section of `r`, to check against
+    fn sections(r: &Range<i8>) -> BTreeSet<i8> {
+        (r.low..=r.high).collect()
+    }
+
+    fn range() -> impl Strategy<Value = Range<i8>> {
+        (any::<i8>(), any::<i8>()).prop_map(|(a, b)| Range::new(a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_range_matches_sections(a in range(), b in range()) {
+            let (sa, sb) = (sections(&a), sections(&b));
+            prop_assert_eq!(a.contains(&b), sb.is_subset(&sa));
+            prop_assert_eq!(a.overlaps(&b), !sa.is_disjoint(&sb));
+            prop_assert_eq!(a.sections(), sa.len() as u128);
+        }
+
+        #[test]
+        fn prop_parse_rejects_inverted(low in any::<u8>(), high in any::<u8>()) {
+            let r = Range::<u8>::try_from(format!("{}-{}", low, high));
+            prop_assert_eq!(r.is_ok(), low <= high);
+            if let Ok(r) = r {
+                prop_assert_eq!(Range::try_from(r.to_string()), Ok(r));
+            }
+        }
+
+        #[test]
+        fn prop_parse_signed_round_trip(low in any::<i8>(), high in any::<i8>()) {
+            let r = Range::new(low.min(high), low.max(high));
+            prop_assert_eq!(Range::try_from(r.to_string()), Ok(r));
+        }
+    }
+}
@@ -0,0 +1,435 @@
+use std::fmt;
+
+use common::{number, tokens, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+struct Stack {
+    v: Vec<String>,
+}
+
+impl Stack {
+    fn new() -> Self {
+        Stack { v: Vec::new() }
+    }
+
+    fn push(&mut self, s: String) {
+        self.v.push(s);
+    }
+
+    fn pop(&mut self) -> Option<String> {
+        self.v.pop()
+    }
+
+    fn top(&self) -> Option<String> {
+        self.v.last().cloned()
+    }
+}
+
+pub struct Move {
+    quantity: u32,
+    source: usize,
+    destination: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "move {} from {} to {}",
+            self.quantity, self.source, self.destination
+        )
+    }
+}
+
+impl TryFrom<String> for Move {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let mut comps = tokens(&s);
+        let end = s.len() + 1;
+        let mut field = |keyword: &str| -> Result<(usize, &str), ParseError> {
+            match comps.next() {
+                Some((_, k)) if k == keyword => {}
+                Some((col, k)) => {
+                    return Err(ParseError::unexpected(col, format!("`{}`", keyword), k))
+                }
+                None => return Err(ParseError::missing(end, format!("`{}`", keyword))),
+            }
+            comps
+                .next()
+                .ok_or_else(|| ParseError::missing(end, "a number"))
+        };
+
+        let (col, quantity) = field("move")?;
+        let quantity = number(col, quantity)?;
+        let (col, source) = field("from")?;
+        let source = number(col, source)?;
+        let (col, destination) = field("to")?;
+        let destination = number(col, destination)?;
+
+        Ok(Self {
+            quantity,
+            source,
+            destination,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Crane {
+    stacks: Vec<Stack>,
+}
+
+impl Crane {
+    fn new() -> Self {
+        Crane { stacks: Vec::new() }
+    }
+
+    fn move_boxes(&mut self, mv: &Move) {
+        (0..mv.quantity).for_each(|_| {
+            let e = self.stacks.get_mut(mv.source - 1).unwrap().pop().unwrap();
+            self.stacks.get_mut(mv.destination - 1).unwrap().push(e);
+        })
+    }
+
+    fn move_boxes_in_order(&mut self, mv: &Move) {
+        let boxes: Vec<String> = (0..mv.quantity)
+            .map(|_| self.stacks.get_mut(mv.source - 1).unwrap().pop().unwrap())
+            .collect();
+        boxes
+            .into_iter()
+            .rev()
+            .for_each(|b| self.stacks.get_mut(mv.destination - 1).unwrap().push(b));
+    }
+}
+
+impl fmt::Display for Crane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let highest_stack = self.stacks.iter().map(|s| s.v.len()).max().unwrap();
+        let mut output = String::new();
+        for i in 0..self.stacks.len() {
+            output.push_str(format!(" {} ", i + 1).as_str());
+            if i < self.stacks.len() {
+                output.push(' ');
+            }
+        }
+        output.push('\n');
+
+        for i in 0..highest_stack {
+            for y in 0..self.stacks.len() {
+                match self.stacks.get(y).unwrap().v.get(i) {
+                    Some(e) => output.push_str(format!("[{}]", e).as_str()),
+                    None => output.push_str("   "),
+                };
+                if y < self.stacks.len() {
+                    output.push(' ');
+                }
+            }
+            output.push('\n');
+        }
+        output.lines().rev().for_each(|l| {
+            writeln!(f, "{}", l).unwrap();
+        });
+        writeln!(f)
+    }
+}
+
+impl TryFrom<String> for Crane {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        let lines: Vec<&str> = s.lines().collect();
+        let mut rev_lines = lines.into_iter().enumerate().rev();
+        let numbers = rev_lines.next();
+        let num = match numbers {
+            Some((_, n)) => n.split_whitespace().count(),
+            None => return Err(ParseError::missing(1, "the stack numbers")),
+        };
+
+        let mut ret = Crane::new();
+
+        ret.stacks.resize_with(num, Stack::new);
+        for (i, l) in rev_lines {
+            let mut counter = 0;
+            let mut col = 0;
+            for (j, c) in l.chars().enumerate() {
+                match c {
+                    '[' => {
+                        counter = 0;
+                    }
+                    ']' => {}
+                    ' ' => {
+                        counter += 1;
+                        counter %= 4;
+                        if counter == 3 {
+                            col += 1;
+                        }
+                    }
+                    c => {
+                        let stack = ret.stacks.get_mut(col).ok_or_else(|| {
+                            ParseError::unexpected(
+                                j + 1,
+                                format!("a box in one of the {} stacks", num),
+                                c,
+                            )
+                            .offset(i, 0)
+                        })?;
+                        stack.push(c.to_string());
+                        col += 1;
+                    }
+                }
+            }
+        }
+
+        Ok(ret)
+    }
+}
+
+fn tops(crane: &Crane) -> String {
+    crane.stacks.iter().filter_map(Stack::top).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Crane, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let (crane_string, move_lines) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::missing(1, "an empty line after the stacks"))?;
+
+        let crane = Crane::try_from(crane_string.to_string())?;
+        let first_move = crane_string.lines().count() + 1;
+        // height of each stack after the moves read so far
+        let mut heights: Vec<usize> = crane.stacks.iter().map(|s| s.v.len()).collect();
+        let mut moves = Vec::new();
+        for (i, mv_string) in move_lines.lines().enumerate() {
+            let mv =
+                Move::try_from(mv_string.to_string()).map_err(|e| e.offset(first_move + i, 0))?;
+            // moves must refer to existing stacks
+            for (n, stack) in [(3, mv.source), (5, mv.destination)] {
+                if stack == 0 || stack > crane.stacks.len() {
+                    let (col, token) = tokens(mv_string).nth(n).unwrap();
+                    return Err(ParseError::unexpected(
+                        col,
+                        format!("a stack between 1 and {}", crane.stacks.len()),
+                        token,
+                    )
+                    .offset(first_move + i, 0));
+                }
+            }
+            // and can't take more crates than the stack holds
+            let quantity = mv.quantity as usize;
+            if quantity > heights[mv.source - 1] {
+                let (col, token) = tokens(mv_string).nth(1).unwrap();
+                return Err(ParseError::unexpected(
+                    col,
+                    format!("at most {} crates", heights[mv.source - 1]),
+                    token,
+                )
+                .offset(first_move + i, 0));
+            }
+            heights[mv.source - 1] -= quantity;
+            heights[mv.destination - 1] += quantity;
+            moves.push(mv);
+        }
+        Ok((crane, moves))
+    }
+
+    fn part1((crane, moves): &Self::Parsed) -> Self::Answer1 {
+        let mut c = crane.clone();
+        moves.iter().for_each(|mv| c.move_boxes(mv));
+        tops(&c)
+    }
+
+    fn part2((crane, moves): &Self::Parsed) -> Self::Answer2 {
+        let mut c = crane.clone();
+        moves.iter().for_each(|mv| c.move_boxes_in_order(mv));
+        tops(&c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let s = Stack::new();
+        assert_eq!(s.top(), None);
+    }
+
+    #[test]
+    fn test_stack_manipulation() {
+        let mut s = Stack::new();
+        assert_eq!(s.top(), None);
+
+        s.push(String::from("A"));
+        assert_eq!(s.top(), Some(String::from("A")));
+
+        s.push(String::from("B"));
+        assert_eq!(s.top(), Some(String::from("B")));
+
+        assert_eq!(s.pop(), Some(String::from("B")));
+        assert_eq!(s.pop(), Some(String::from("A")));
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test]
+    fn test_crane_init() {
+        let s = String::from(
+            "    [P]                 [Q]     [T]
+[F] [N]             [P] [L]     [M]
+[H] [T] [H]         [M] [H]     [Z]
+[M] [C] [P]     [Q] [R] [C]     [J]
+[T] [J] [M] [F] [L] [G] [R]     [Q]
+[V] [G] [D] [V] [G] [D] [N] [W] [L]
+[L] [Q] [S] [B] [H] [B] [M] [L] [D]
+[D] [H] [R] [L] [N] [W] [G] [C] [R]
+ 1   2   3   4   5   6   7   8   9 ",
+        );
+        let crane = Crane::try_from(s);
+        assert!(crane.is_ok());
+        assert_eq!(crane.unwrap().stacks.len(), 9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_crate_init_panic() {
+        let s2 = String::from(
+            "                    [Q]     [T]
+[F] [N]             [P] [L]     [M]
+[H] [T] [H]         [M] [H]     [Z]
+[M] [C] [P]     [Q] [R] [C]     [J]
+[T] [J] [M] [F] [L] [G] [R]     [Q]
+[V] [G] [D] [V] [G] [D] [N] [W] [L]
+[L] [Q] [S] [B] [H] [B] [M] [L] [D]
+[D] [H] [R] [L] [N] [W] [G] [C] [R]
+ 1   2   3   4   5   7   8   9 ",
+        );
+        let crane2 = Crane::try_from(s2);
+        assert!(crane2.is_ok());
+    }
+
+    #[test]
+    fn test_crane_move() {
+        let s = String::from(
+            "    [P]                 [Q]     [T]
+[F] [N]             [P] [L]     [M]
+[H] [T] [H]         [M] [H]     [Z]
+[M] [C] [P]     [Q] [R] [C]     [J]
+[T] [J] [M] [F] [L] [G] [R]     [Q]
+[V] [G] [D] [V] [G] [D] [N] [W] [L]
+[L] [Q] [S] [B] [H] [B] [M] [L] [D]
+[D] [H] [R] [L] [N] [W] [G] [C] [R]
+ 1   2   3   4   5   6   7   8   9 ",
+        );
+
+        let mut crane = Crane::try_from(s).unwrap();
+        assert_eq!(crane.stacks.len(), 9);
+        assert_eq!(
+            crane.stacks.last().unwrap().top().unwrap(),
+            String::from("T")
+        );
+        assert_eq!(
+            crane.stacks.get(3).unwrap().top().unwrap(),
+            String::from("F")
+        );
+        crane.move_boxes(&Move {
+            quantity: 2,
+            source: 9,
+            destination: 4,
+        });
+
+        assert_eq!(
+            crane.stacks.last().unwrap().top().unwrap(),
+            String::from("Z")
+        );
+        assert_eq!(
+            crane.stacks.get(3).unwrap().top().unwrap(),
+            String::from("M")
+        );
+    }
+
+    #[test]
+    fn test_crane_move_in_order() {
+        let s = String::from(
+            "    [P]                 [Q]     [T]
+[F] [N]             [P] [L]     [M]
+[H] [T] [H]         [M] [H]     [Z]
+[M] [C] [P]     [Q] [R] [C]     [J]
+[T] [J] [M] [F] [L] [G] [R]     [Q]
+[V] [G] [D] [V] [G] [D] [N] [W] [L]
+[L] [Q] [S] [B] [H] [B] [M] [L] [D]
+[D] [H] [R] [L] [N] [W] [G] [C] [R]
+ 1   2   3   4   5   6   7   8   9 ",
+        );
+
+        let mut crane = Crane::try_from(s).unwrap();
+        assert_eq!(crane.stacks.len(), 9);
+        assert_eq!(
+            crane.stacks.last().unwrap().top().unwrap(),
+            String::from("T")
+        );
+        assert_eq!(
+            crane.stacks.get(3).unwrap().top().unwrap(),
+            String::from("F")
+        );
+        crane.move_boxes_in_order(&Move {
+            quantity: 2,
+            source: 9,
+            destination: 4,
+        });
+
+        assert_eq!(
+            crane.stacks.last().unwrap().top().unwrap(),
+            String::from("Z")
+        );
+        assert_eq!(
+            crane.stacks.get(3).unwrap().top().unwrap(),
+            String::from("T")
+        );
+    }
+
+    #[test]
+    fn parse_move() {
+        let input = String::from("move 1 from 2 to 3");
+        let mv = Move::try_from(input);
+
+        assert!(mv.is_ok());
+        let mv = mv.unwrap();
+        assert_eq!(mv.quantity, 1);
+        assert_eq!(mv.source, 2);
+        assert_eq!(mv.destination, 3);
+    }
+
+    #[test]
+    fn parse_move_errors() {
+        let mv = Move::try_from(String::from("move 1 to 2"));
+        assert_eq!(mv.err(), Some(ParseError::unexpected(8, "`from`", "to")));
+        let mv = Move::try_from(String::from("move x from 2 to 3"));
+        assert_eq!(mv.err(), Some(ParseError::unexpected(6, "a number", "x")));
+        let mv = Move::try_from(String::from("move 1 from 2"));
+        assert_eq!(mv.err(), Some(ParseError::missing(14, "`to`")));
+    }
+
+    #[test]
+    fn parse_impossible_move() {
+        let stacks = "[A]    \n[B] [C]\n 1   2 \n\n";
+        let e = Day5::parse(&format!(
+            "{}move 1 from 2 to 1\nmove  2 from 2 to 1",
+            stacks
+        ));
+        assert_eq!(
+            e.err(),
+            Some(ParseError::unexpected(7, "at most 0 crates", "2").offset(5, 0))
+        );
+        let parsed = Day5::parse(&format!("{}move 3 from 1 to 2\nmove 2 from 2 to 1", stacks));
+        assert!(parsed.is_err());
+        let parsed = Day5::parse(&format!("{}move 2 from 1 to 2\nmove 3 from 2 to 1", stacks));
+        assert_eq!(Day5::part1(&parsed.unwrap()), "C");
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use common::{ParseError, Solution};
+
+fn find_start_packet(input: &str, consecutive: usize) -> usize {
+    let mut ret = 0;
+    let mut found = false;
+    let mut i = consecutive - 1;
+    while i < input.len() && !found {
+        let mut set: HashSet<char> = HashSet::new();
+        ret = i;
+        for j in 0..consecutive {
+            let c = input.chars().nth(i - j).unwrap();
+            if set.contains(&c) {
+                break;
+            }
+            set.insert(c);
+            if set.len() == consecutive {
+                found = true;
+            }
+        }
+        i += 1;
+    }
+    ret + 1
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(signal: &Self::Parsed) -> Self::Answer1 {
+        find_start_packet(signal, 4)
+    }
+
+    fn part2(signal: &Self::Parsed) -> Self::Answer2 {
+        find_start_packet(signal, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::find_start_packet;
+
+    #[test]
+    fn test_find_start_packet() {
+        let inputs = vec![
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 4, 5),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 4, 6),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 4, 10),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 4, 11),
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 14, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 14, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 14, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 14, 26),
+        ];
+
+        inputs
+            .iter()
+            .for_each(|test| assert_eq!(find_start_packet(test.0, test.1), test.2))
+    }
+}
@@ -0,0 +1,263 @@
+use core::fmt;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use common::{number, tokens, ParseError, Solution};
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    ChangeDir(String),
+    List,
+}
+
+impl TryFrom<String> for Command {
+    type Error = ParseError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut split = tokens(&value);
+        let end = value.len() + 1;
+        match split.next() {
+            Some((_, "$")) => {}
+            Some((col, t)) => return Err(ParseError::unexpected(col, "`$`", t)),
+            None => return Err(ParseError::missing(end, "`$`")),
+        }
+        match split.next() {
+            Some((_, "ls")) => Ok(Self::List),
+            Some((_, "cd")) => match split.next() {
+                Some((_, dir)) => Ok(Self::ChangeDir(dir.to_string())),
+                None => Err(ParseError::missing(end, "a directory name")),
+            },
+            Some((col, t)) => Err(ParseError::unexpected(col, "`cd` or `ls`", t)),
+            None => Err(ParseError::missing(end, "`cd` or `ls`")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum NodeType {
+    Directory(String),
+    File((String, usize)),
+}
+
+impl TryFrom<String> for NodeType {
+    type Error = ParseError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut split = tokens(&value);
+        let end = value.len() + 1;
+        let name = |split: Option<(usize, &str)>| {
+            split
+                .map(|(_, name)| name.to_string())
+                .ok_or_else(|| ParseError::missing(end, "a name"))
+        };
+        match split.next() {
+            Some((_, "dir")) => Ok(Self::Directory(name(split.next())?)),
+            Some((col, c)) => {
+                let size = number(col, c)?;
+                Ok(Self::File((name(split.next())?, size)))
+            }
+            None => Err(ParseError::missing(end, "`dir` or a file size")),
+        }
+    }
+}
+
+impl fmt::Display for NodeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directory(name) => write!(f, "- {} (dir,", name),
+            Self::File((name, _)) => write!(f, "- {} (file,", name),
+        }
+    }
+}
+
+pub struct Node {
+    value: NodeType,
+    level: u32,
+    size: usize,
+    parent: Option<Rc<RefCell<Node>>>,
+    children: Vec<Rc<RefCell<Node>>>,
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} size={})", self.value, self.size)?;
+        self.children.iter().for_each(|c| {
+            for _ in 0..c.borrow().level {
+                write!(f, "  ").unwrap();
+            }
+            write!(f, "{}", c.borrow()).unwrap();
+        });
+        write!(f, "")
+    }
+}
+
+impl Node {
+    fn new_ref(node_type: NodeType) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Node {
+            value: node_type,
+            level: 0,
+            parent: None,
+            children: Vec::new(),
+            size: 0,
+        }))
+    }
+
+    fn add_child(parent: Rc<RefCell<Node>>, child: Rc<RefCell<Node>>) {
+        let node_to_insert = Rc::clone(&child);
+        if node_to_insert.borrow().parent.is_some() {
+            return;
+        }
+        node_to_insert.borrow_mut().level = parent.borrow().level + 1;
+        node_to_insert.borrow_mut().parent = Some(Rc::clone(&parent));
+        parent.borrow_mut().children.push(node_to_insert.to_owned());
+    }
+
+    fn du(&mut self) -> usize {
+        self.size = match self.value.clone() {
+            NodeType::Directory(_) => self.children.iter().map(|c| c.borrow_mut().du()).sum(),
+            NodeType::File((_, s)) => s,
+        };
+        self.size
+    }
+
+    // run du on root before!
+    fn ex1(&self) -> usize {
+        let contrib = match self.value.clone() {
+            NodeType::Directory(_) if self.size < 100000 => self.size,
+            _ => 0,
+        };
+        let children: usize = self.children.iter().map(|c| c.borrow_mut().ex1()).sum();
+        contrib + children
+    }
+
+    // run du on root before!
+    fn free(&self, free: usize, required: usize, sizes: &mut Vec<usize>) {
+        match self.value.clone() {
+            NodeType::Directory(_) if free + self.size > required => sizes.push(self.size),
+            _ => (),
+        };
+        self.children
+            .iter()
+            .for_each(|c| c.borrow().free(free, required, sizes));
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Rc<RefCell<Node>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        let root = Node::new_ref(NodeType::Directory(String::from("/")));
+        root.borrow_mut().parent = Some(Rc::clone(&root));
+        let mut current_node = Rc::clone(&root);
+
+        for (i, line) in input.lines().enumerate() {
+            if line.starts_with('$') {
+                let command = Command::try_from(line.to_string()).map_err(|e| e.offset(i, 0))?;
+                if let Command::ChangeDir(dir_name) = command {
+                    match dir_name.as_str() {
+                        "/" => current_node = Rc::clone(&root),
+                        ".." => {
+                            let next = current_node.borrow().parent.clone().unwrap();
+                            current_node = next;
+                        }
+                        s => {
+                            let position =
+                                current_node.borrow().children.iter().position(|node| {
+                                    matches!(&node.borrow().value, NodeType::Directory(name) if name.as_str() == s)
+                                });
+                            match position {
+                                Some(n) => {
+                                    let next_node = current_node.borrow().children[n].clone();
+                                    current_node = next_node;
+                                }
+                                None => {
+                                    let col = line.rfind(s).unwrap() + 1;
+                                    return Err(ParseError::unexpected(
+                                        col,
+                                        "a listed directory",
+                                        s,
+                                    )
+                                    .offset(i, 0));
+                                }
+                            };
+                        }
+                    }
+                }
+            } else {
+                let node_type = NodeType::try_from(line.to_string()).map_err(|e| e.offset(i, 0))?;
+                Node::add_child(Rc::clone(&current_node), Node::new_ref(node_type));
+            }
+        }
+
+        root.borrow_mut().du();
+        Ok(root)
+    }
+
+    fn part1(root: &Self::Parsed) -> Self::Answer1 {
+        root.borrow().ex1()
+    }
+
+    fn part2(root: &Self::Parsed) -> Self::Answer2 {
+        let mut sizes: Vec<usize> = Vec::new();
+        root.borrow()
+            .free(70000000 - root.borrow().size, 30000000, &mut sizes);
+        *sizes.iter().min().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    #[test]
+    fn try_from_command() {
+        let c = Command::try_from(String::from("$ cd /"));
+        assert_eq!(c.unwrap(), Command::ChangeDir(String::from("/")));
+
+        let c = Command::try_from(String::from("$ cd .."));
+        assert_eq!(c.unwrap(), Command::ChangeDir(String::from("..")));
+
+        let c = Command::try_from(String::from("$ ls"));
+        assert_eq!(c.unwrap(), Command::List);
+    }
+
+    #[test]
+    #[should_panic]
+    fn try_from_command_fails() {
+        Command::try_from(String::from("$ cd")).unwrap();
+    }
+
+    #[test]
+    fn try_from_command_fails_2() {
+        let c = Command::try_from(String::from("$"));
+        assert!(c.is_err())
+    }
+
+    #[test]
+    fn parse_unknown_directory() {
+        let e = Day7::parse("$ cd /\n$ ls\ndir a\n$ cd b").err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(6, "a listed directory", "b").offset(3, 0)
+        );
+    }
+
+    #[test]
+    fn test_try_from_node_type() {
+        let s = String::from("dir a");
+        let t = NodeType::try_from(s);
+        assert_eq!(t.unwrap(), NodeType::Directory(String::from("a")));
+
+        let s = String::from("1290 ac");
+        let t = NodeType::try_from(s);
+        assert_eq!(t.unwrap(), NodeType::File((String::from("ac"), 1290)));
+
+        let t = NodeType::try_from(String::from("a b"));
+        assert!(t.is_err());
+
+        let t = NodeType::try_from(String::from("dir"));
+        assert_eq!(t, Err(ParseError::missing(4, "a name")));
+    }
+}
@@ -0,0 +1,137 @@
+use std::cmp;
+
+use common::{ParseError, Solution};
+use geometry::Direction;
+use grid::Grid;
+
+pub struct Forest {
+    trees: Grid<u32>,
+}
+
+impl TryFrom<String> for Forest {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let trees = Grid::parse(&value, "a digit", |c| c.to_digit(10))?;
+        Ok(Forest { trees })
+    }
+}
+
+pub trait CountWhileExt: Iterator {
+    fn count_while<P: Fn(Self::Item) -> bool>(self, predicate: P) -> usize;
+}
+
+// apply trait to all types implementing the Iterator trait.
+impl<I> CountWhileExt for I
+where
+    I: Iterator,
+    I::Item: Copy + Clone + Ord,
+{
+    fn count_while<P: Fn(Self::Item) -> bool>(self, predicate: P) -> usize {
+        let mut count_ended = false;
+        self.fold(0, |acc, x| {
+            if predicate(x) && !count_ended {
+                acc + 1
+            } else if !count_ended {
+                count_ended = true;
+                acc + 1
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl Forest {
+    fn visible(&self) -> usize {
+        self.trees
+            .iter()
+            .filter(|&(pos, &height)| {
+                Direction::ALL
+                    .iter()
+                    .any(|&d| self.trees.ray(pos, d.offset()).all(|&x| x < height))
+            })
+            .count()
+    }
+
+    fn scenic_score(&self) -> usize {
+        self.trees.iter().fold(0, |max, (pos, &height)| {
+            let score = Direction::ALL
+                .iter()
+                .map(|&d| {
+                    self.trees
+                        .ray(pos, d.offset())
+                        .copied()
+                        .count_while(|x| x < height)
+                })
+                .product();
+            cmp::max(max, score)
+        })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Forest;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Forest::try_from(input.to_string())
+    }
+
+    fn part1(forest: &Self::Parsed) -> Self::Answer1 {
+        forest.visible()
+    }
+
+    fn part2(forest: &Self::Parsed) -> Self::Answer2 {
+        forest.scenic_score()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{CountWhileExt, Forest, ParseError};
+
+    #[test]
+    fn test_forest_parse() {
+        let forest = Forest::try_from("213\n321".to_string()).unwrap();
+        assert_eq!(forest.trees.row(0).copied().collect::<Vec<_>>(), [2, 1, 3]);
+        assert_eq!(forest.trees.row(1).copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+        let e = Forest::try_from("213\n3x1".to_string()).err().unwrap();
+        assert_eq!(e, ParseError::unexpected(2, "a digit", "x").offset(1, 0));
+    }
+
+    #[test]
+    fn test_count_visible() {
+        let trees = "30373
+25512
+65332
+33549
+35390";
+        let forest = Forest::try_from(trees.to_string()).unwrap();
+        assert_eq!(forest.visible(), 21);
+    }
+
+    #[test]
+    fn test_count_until() {
+        let v = vec![1, 2, 3, 4, 5];
+        assert_eq!(v.into_iter().count_while(|x| x <= 3), 4);
+        let v = vec![1, 2, 3, 4, 5];
+        assert_eq!(v.into_iter().count_while(|x| x < 1), 1);
+    }
+
+    #[test]
+    fn test_score() {
+        let trees = "30373
+25512
+65332
+33549
+35390";
+        let forest = Forest::try_from(trees.to_string()).unwrap();
+        assert_eq!(forest.scenic_score(), 8);
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use common::{number, tokens, ParseError, Solution};
+use geometry::{Direction, Point};
+
+type Position = Point<i32>;
+
+#[derive(Debug, PartialEq)]
+pub enum Move {
+    Up(i32),
+    Down(i32),
+    Left(i32),
+    Right(i32),
+}
+
+impl Move {
+    fn direction(&self) -> Direction {
+        match self {
+            Move::Up(_) => Direction::Up,
+            Move::Down(_) => Direction::Down,
+            Move::Left(_) => Direction::Left,
+            Move::Right(_) => Direction::Right,
+        }
+    }
+
+    fn move_quantity(&self) -> i32 {
+        match self {
+            Move::Up(i) => *i,
+            Move::Down(i) => *i,
+            Move::Left(i) => *i,
+            Move::Right(i) => *i,
+        }
+    }
+}
+
+impl TryFrom<String> for Move {
+    type Error = ParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut split = tokens(&value);
+        let end = value.len() + 1;
+        let (dir_col, move_direction) = split
+            .next()
+            .ok_or_else(|| ParseError::missing(end, "a direction"))?;
+        let (col, move_quantity) = split
+            .next()
+            .ok_or_else(|| ParseError::missing(end, "a number"))?;
+        let move_quantity = number(col, move_quantity)?;
+
+        match move_direction {
+            "R" => Ok(Move::Right(move_quantity)),
+            "L" => Ok(Move::Left(move_quantity)),
+            "U" => Ok(Move::Up(move_quantity)),
+            "D" => Ok(Move::Down(move_quantity)),
+            d => Err(ParseError::unexpected(dir_col, "one of R L U D", d)),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Bridge {
+    positions: Vec<Position>,
+    visited: HashSet<Position>,
+}
+
+impl Bridge {
+    fn new(num: usize) -> Self {
+        let mut ret = Bridge {
+            positions: vec![Position::origin(); num],
+            visited: HashSet::new(),
+        };
+        ret.visited.insert(*ret.positions.iter().last().unwrap());
+        ret
+    }
+
+    fn calc_move_tail(head: &Position, tail: &Position) -> Position {
+        if head.adjacent(tail) || tail == head {
+            return Position::origin();
+        }
+        (*head - *tail).signum()
+    }
+
+    fn make_move(&mut self, mv: &Move) {
+        let qty = mv.move_quantity();
+        for _ in 0..qty {
+            self.positions[0] += mv.direction().offset();
+
+            for i in 1..self.positions.len() {
+                let pos_mv = Bridge::calc_move_tail(&self.positions[i - 1], &self.positions[i]);
+                self.positions[i] += pos_mv;
+            }
+
+            self.visited
+                .insert(self.positions[self.positions.len() - 1]);
+        }
+    }
+}
+
+fn visited_by_tail(moves: &[Move], knots: usize) -> usize {
+    let mut bridge = Bridge::new(knots);
+    moves.iter().for_each(|mv| {
+        bridge.make_move(mv);
+    });
+    bridge.visited.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<Move>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Move::try_from(l.to_string()).map_err(|e| e.offset(i, 0)))
+            .collect()
+    }
+
+    fn part1(moves: &Self::Parsed) -> Self::Answer1 {
+        visited_by_tail(moves, 2)
+    }
+
+    fn part2(moves: &Self::Parsed) -> Self::Answer2 {
+        visited_by_tail(moves, 10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_move_parse() {
+        assert_eq!(Move::try_from("R 10".to_string()), Ok(Move::Right(10)));
+        assert_eq!(Move::try_from("L 10".to_string()), Ok(Move::Left(10)));
+        assert_eq!(Move::try_from("U 10".to_string()), Ok(Move::Up(10)));
+        assert_eq!(Move::try_from("D 10".to_string()), Ok(Move::Down(10)));
+        assert_eq!(Move::try_from("R 100".to_string()), Ok(Move::Right(100)));
+        assert_eq!(Move::try_from("L 100".to_string()), Ok(Move::Left(100)));
+        assert_eq!(
+            Move::try_from("X 1".to_string()),
+            Err(ParseError::unexpected(1, "one of R L U D", "X"))
+        );
+        assert_eq!(
+            Move::try_from("R".to_string()),
+            Err(ParseError::missing(2, "a number"))
+        );
+    }
+
+    #[test]
+    fn test_calc_move_tail() {
+        let tail = Position::new(1, 1);
+        assert_eq!(
+            Bridge::calc_move_tail(&Position::new(2, 2), &tail),
+            Position::origin()
+        );
+        assert_eq!(
+            Bridge::calc_move_tail(&Position::new(3, 1), &tail),
+            Position::new(1, 0)
+        );
+        assert_eq!(
+            Bridge::calc_move_tail(&Position::new(2, -1), &tail),
+            Position::new(1, -1)
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = Position::new(4, 4);
+        assert!(p.adjacent(&Position::new(4, 3)));
+        assert!(p.adjacent(&Position::new(3, 3)));
+        assert!(p.adjacent(&Position::new(5, 5)));
+        assert!(!p.adjacent(&Position::new(2, 3)));
+    }
+}
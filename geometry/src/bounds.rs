@@ -0,0 +1,58 @@
+use crate::{Coord, Point};
+
+/// Smallest axis-aligned rectangle holding a set of points, bounds included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coord> Bounds<T> {
+    /// The bounds of `points`, `None` when there are none.
+    pub fn from_points<I: IntoIterator<Item = Point<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Bounds {
+            min: first,
+            max: first,
+        };
+        points.for_each(|p| bounds.extend(p));
+        Some(bounds)
+    }
+
+    /// Grows the bounds so that they hold `p`.
+    pub fn extend(&mut self, p: Point<T>) {
+        self.min = Point::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+
+    pub fn contains(&self, p: &Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Bounds::<i32>::from_points([]), None);
+
+        let points = [Point::new(1, -2), Point::new(-3, 4), Point::new(0, 0)];
+        let bounds = Bounds::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point::new(-3, -2));
+        assert_eq!(bounds.max, Point::new(1, 4));
+        assert_eq!((bounds.width(), bounds.height()), (5, 7));
+        assert!(points.iter().all(|p| bounds.contains(p)));
+        assert!(!bounds.contains(&Point::new(2, 0)));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Point, Signed};
+
+/// The four orthogonal directions, on a plane where `y` grows downward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step of length 1 going in this direction.
+    pub fn offset<T: Signed>(self) -> Point<T> {
+        match self {
+            Direction::Up => Point::new(T::ZERO, -T::ONE),
+            Direction::Right => Point::new(T::ONE, T::ZERO),
+            Direction::Down => Point::new(T::ZERO, T::ONE),
+            Direction::Left => Point::new(-T::ONE, T::ZERO),
+        }
+    }
+}
+
+/// The eight compass directions, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from `N`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// The step going in this direction, of length 1 on each moving axis.
+    pub fn offset<T: Signed>(self) -> Point<T> {
+        let up = Direction::Up.offset::<T>();
+        let right = Direction::Right.offset::<T>();
+        match self {
+            Direction8::N => up,
+            Direction8::NE => up + right,
+            Direction8::E => right,
+            Direction8::SE => right - up,
+            Direction8::S => -up,
+            Direction8::SW => -up - right,
+            Direction8::W => -right,
+            Direction8::NW => up - right,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(d: Direction) -> Self {
+        Direction8::ALL[d as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        for d in Direction::ALL {
+            assert_eq!(d.offset::<i32>().rotate_right(), d.turn_right().offset());
+            assert_eq!(Direction8::from(d).offset::<i32>(), d.offset());
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Direction8::NE.offset::<i64>(), Point::new(1, -1));
+        assert_eq!(Direction8::SW.offset::<i64>(), Point::new(-1, 1));
+        let origin = Point::<i64>::origin();
+        assert!(Direction8::ALL.iter().all(|d| origin.adjacent(&d.offset())));
+    }
+}
@@ -0,0 +1,7 @@
+mod bounds;
+mod direction;
+mod point;
+
+pub use bounds::Bounds;
+pub use direction::{Direction, Direction8};
+pub use point::{Coord, Point, Signed};
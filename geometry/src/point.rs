@@ -0,0 +1,200 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Integer type usable as a point coordinate.
+pub trait Coord: Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self;
+}
+
+/// Coordinate that can be negative, used for offsets and rotations.
+pub trait Signed: Coord + Neg<Output = Self> {
+    fn signum(self) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {$(
+        impl Coord for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs_diff(self, other: Self) -> Self {
+                self.max(other) - self.min(other)
+            }
+        }
+    )*};
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+coord!(i32, i64, isize, u32, u64, usize);
+signed!(i32, i64, isize);
+
+/// Point on a plane where `y` grows downward, as on a screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Coord> Point<T> {
+    pub fn origin() -> Self {
+        Point::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// True when `other` is one of the 8 points around `self`.
+    pub fn adjacent(&self, other: &Self) -> bool {
+        self.chebyshev(other) == T::ONE
+    }
+}
+
+impl<T: Signed> Point<T> {
+    /// Quarter turn counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Point::new(self.y, -self.x)
+    }
+
+    /// Quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Point::new(-self.y, self.x)
+    }
+
+    /// The point with each coordinate replaced by its sign, that is the
+    /// single step going in the direction of `self`.
+    pub fn signum(self) -> Self {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl Point<usize> {
+    /// Moves by a signed offset, unless that goes below zero.
+    pub fn checked_add_signed(self, offset: Point<isize>) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(Point::new(1, 1) + Point::new(-2, -3), Point::new(-1, -2));
+        assert_eq!(Point::new(1, 1) - Point::new(-2, -3), Point::new(3, 4));
+        let mut p = Point::new(1, 1);
+        p += Point::new(-2, -3);
+        assert_eq!(p, Point::new(-1, -2));
+        assert_eq!(-p * 3, Point::new(3, 6));
+    }
+
+    #[test]
+    fn test_distances() {
+        let p = Point::new(4u32, 4);
+        assert_eq!(p.manhattan(&Point::new(1, 6)), 5);
+        assert_eq!(p.chebyshev(&Point::new(1, 6)), 3);
+        assert!(p.adjacent(&Point::new(3, 3)));
+        assert!(p.adjacent(&Point::new(5, 5)));
+        assert!(!p.adjacent(&p));
+        assert!(!p.adjacent(&Point::new(2, 3)));
+    }
+
+    #[test]
+    fn test_rotations() {
+        let p = Point::new(2, 1);
+        assert_eq!(p.rotate_left(), Point::new(1, -2));
+        assert_eq!(p.rotate_right(), Point::new(-1, 2));
+        assert_eq!(p.rotate_left().rotate_right(), p);
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+    }
+
+    #[test]
+    fn test_checked_add_signed() {
+        let p = Point::new(0usize, 2);
+        assert_eq!(
+            p.checked_add_signed(Point::new(1, -2)),
+            Some(Point::new(1, 0))
+        );
+        assert_eq!(p.checked_add_signed(Point::new(-1, 0)), None);
+    }
+}
@@ -0,0 +1,259 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use common::ParseError;
+use geometry::{Direction, Direction8, Point};
+
+/// Position of a cell, with `(0, 0)` the top-left corner.
+pub type Pos = Point<usize>;
+
+/// Rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, one row per line. Leading and trailing
+    /// whitespace is ignored, as are the empty lines at the end, and every
+    /// row must have the same width.
+    /// `cell` returns `None` for characters that are not `expected`.
+    pub fn parse<F>(input: &str, expected: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        let lines: Vec<&str> = input.lines().collect();
+        let end = lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .map_or(0, |i| i + 1);
+        for (i, line) in lines[..end].iter().enumerate() {
+            let indent = line.len() - line.trim_start().len();
+            let mut row = 0;
+            for (j, c) in line.trim().chars().enumerate() {
+                if width == Some(j) {
+                    return Err(
+                        ParseError::unexpected(indent + j + 1, "end of line", c).offset(i, 0)
+                    );
+                }
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::unexpected(indent + j + 1, expected, c).offset(i, 0)
+                })?;
+                cells.push(value);
+                row += 1;
+            }
+            match width {
+                Some(w) if w != row => {
+                    return Err(ParseError::missing(indent + row + 1, expected).offset(i, 0))
+                }
+                _ => width = Some(row),
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    fn index_of(&self, Point { x, y }: Pos) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| x + y * self.width)
+    }
+
+    /// Moves `pos` by `offset`, if the result is still on the grid.
+    pub fn step(&self, pos: Pos, offset: Point<isize>) -> Option<Pos> {
+        let next = pos.checked_add_signed(offset)?;
+        self.index_of(next).map(|_| next)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The up to 4 orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d.offset()))
+    }
+
+    /// The up to 8 neighbours of `pos`, diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d.offset()))
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// Panics if `x` is past the last column.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of the grid", x);
+        self.cells[x..].iter().step_by(self.width).take(self.height)
+    }
+
+    /// The cells met going from `pos` (excluded) by steps of `offset`
+    /// until the edge of the grid.
+    pub fn ray(&self, pos: Pos, offset: Point<isize>) -> impl Iterator<Item = &T> {
+        let mut current = pos;
+        std::iter::from_fn(move || {
+            current = self.step(current, offset)?;
+            Some(&self[current])
+        })
+    }
+
+    /// Draws the grid with one character per cell, one line per row.
+    pub fn render<F: Fn(&T) -> char>(&self, draw: F) -> String {
+        let mut out = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            out.extend(self.row(y).map(&draw));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos));
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos));
+        &mut self.cells[i]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for c in self.row(y) {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n  456\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(0, 1)], 4);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(digits("123\n456\n\n  \n").unwrap(), grid);
+        assert!(digits("123\n\n456").is_err());
+
+        let e = digits("123\n4x6").err().unwrap();
+        assert_eq!(e, ParseError::unexpected(2, "a digit", "x").offset(1, 0));
+        let e = digits("123\n45").err().unwrap();
+        assert_eq!(e, ParseError::missing(3, "a digit").offset(1, 0));
+        let e = digits("12\n345").err().unwrap();
+        assert_eq!(
+            e,
+            ParseError::unexpected(3, "end of line", "5").offset(1, 0)
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n789").unwrap();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = digits("123\n456\n789").unwrap();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(
+            grid.ray(Point::new(2, 2), Direction8::NW.offset())
+                .copied()
+                .collect::<Vec<_>>(),
+            [5, 1]
+        );
+        assert_eq!(
+            grid.ray(Point::new(0, 1), Direction::Left.offset()).count(),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_out_of_grid() {
+        digits("123\n456").unwrap().column(3).count();
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = Grid::new(3, 2, false);
+        grid[Point::new(1, 0)] = true;
+        assert_eq!(grid.render(|&on| if on { '#' } else { '.' }), ".#.\n...\n");
+        assert_eq!(digits("12\n34").unwrap().to_string(), "12\n34\n");
+    }
+}